@@ -0,0 +1,83 @@
+use std::process::ExitCode;
+
+use aoc2015::day07::{get_unread_wires, parse_instructions, to_dot, to_source, trace, Word};
+use aoc_core::input::InputSource;
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(about = "Inspect the wire circuit of 2015 day 7")]
+struct Cli {
+    #[command(subcommand)]
+    view: CircuitView,
+    /// Read the circuit from this file instead, or from stdin when given `-`
+    #[arg(long, global = true)]
+    input: Option<InputSource>,
+    /// Number of bits carried by every wire: 8, 16, 32 or 64
+    #[arg(long, global = true, default_value_t = 16)]
+    bits: u32,
+}
+
+#[derive(Subcommand)]
+enum CircuitView {
+    /// Print the circuit as a Graphviz DOT graph
+    Dot,
+    /// Print the expression tree that feeds a wire, with the signal on every wire
+    Trace { wire: String },
+    /// List the wires that no gate reads
+    Unread,
+    /// Print the instructions in the syntax of the input
+    Print,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let source = cli.input.unwrap_or_else(|| InputSource::for_day(2015, 7));
+    let data = match source.read() {
+        Ok(data) => data,
+        Err(err) => {
+            eprintln!("Could not read input from {source}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match cli.bits {
+        8 => inspect_circuit::<u8>(cli.view, &data, &source),
+        16 => inspect_circuit::<u16>(cli.view, &data, &source),
+        32 => inspect_circuit::<u32>(cli.view, &data, &source),
+        64 => inspect_circuit::<u64>(cli.view, &data, &source),
+        bits => {
+            eprintln!("Wires can only carry 8, 16, 32 or 64 bits, not {bits}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn inspect_circuit<W: Word>(view: CircuitView, data: &str, source: &InputSource) -> ExitCode {
+    let instructions = match parse_instructions::<W>(data) {
+        Ok(instructions) => instructions,
+        Err(err) => {
+            eprintln!("Could not parse circuit from {source}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match view {
+        CircuitView::Dot => print!("{}", to_dot(&instructions)),
+        CircuitView::Trace { wire } => match trace(&instructions, &wire) {
+            Ok(tree) => print!("{tree}"),
+            Err(err) => {
+                eprintln!("Could not trace wire {wire}: {err}");
+                return ExitCode::FAILURE;
+            }
+        },
+        CircuitView::Unread => {
+            for wire in get_unread_wires(&instructions) {
+                println!("{wire}");
+            }
+        }
+        CircuitView::Print => print!("{}", to_source(&instructions)),
+    }
+
+    ExitCode::SUCCESS
+}
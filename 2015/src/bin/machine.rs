@@ -0,0 +1,131 @@
+use std::{
+    io::{self, Write},
+    process::ExitCode,
+};
+
+use aoc2015::day23::{
+    disassemble, get_registers, parse_instructions, DebugCommand, Debugger, Runtime, MAX_STEPS,
+};
+use aoc_core::input::InputSource;
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(about = "Inspect the register machine program of 2015 day 23")]
+struct Cli {
+    #[command(subcommand)]
+    view: MachineView,
+    /// Read the program from this file instead, or from stdin when given `-`
+    #[arg(long, global = true)]
+    input: Option<InputSource>,
+}
+
+#[derive(Subcommand)]
+enum MachineView {
+    /// Print the program with the index of every instruction and the target of every jump
+    Disassemble,
+    /// Run the program and print every instruction with the registers before it
+    Trace {
+        /// Initial value of register a
+        #[arg(long, default_value_t = 0)]
+        a: u64,
+        /// Number of instructions after which the program is stopped
+        #[arg(long, default_value_t = MAX_STEPS)]
+        max_steps: usize,
+    },
+    /// Step through the program interactively, type `help` for the commands
+    Debug {
+        /// Initial value of register a
+        #[arg(long, default_value_t = 0)]
+        a: u64,
+        /// Number of instructions after which the program is stopped
+        #[arg(long, default_value_t = MAX_STEPS)]
+        max_steps: usize,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let source = cli.input.unwrap_or_else(|| InputSource::for_day(2015, 23));
+    let data = match source.read() {
+        Ok(data) => data,
+        Err(err) => {
+            eprintln!("Could not read input from {source}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let instructions = match parse_instructions(&data) {
+        Ok(instructions) => instructions,
+        Err(err) => {
+            eprintln!("Could not parse program from {source}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match cli.view {
+        MachineView::Disassemble => print!("{}", disassemble(&instructions)),
+        MachineView::Trace { a, max_steps } => {
+            let registers = get_registers(&instructions);
+            let mut runtime = Runtime::new();
+            runtime.set_register('a', a);
+
+            let result = runtime.run_with(&instructions, max_steps, |runtime, _instruction| {
+                println!("{}", runtime.trace_line(&instructions, &registers));
+            });
+            println!("{}", runtime.trace_line(&instructions, &registers));
+            if let Err(err) = result {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        }
+        MachineView::Debug { a, max_steps } => {
+            if matches!(source, InputSource::Stdin) {
+                eprintln!("The debugger reads its commands from stdin, so the program has to come from a file");
+                return ExitCode::FAILURE;
+            }
+
+            let mut runtime = Runtime::new();
+            runtime.set_register('a', a);
+            debug(Debugger::new(&instructions, runtime, max_steps));
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn debug(mut debugger: Debugger) {
+    print!("{}", debugger.execute(DebugCommand::Print));
+
+    // An empty line repeats the previous command, like stepping on in gdb
+    let mut previous = None;
+    let mut lines = io::stdin().lines();
+    loop {
+        print!("(debug) ");
+        io::stdout().flush().expect("Could not write to stdout");
+
+        let Some(Ok(line)) = lines.next() else {
+            println!();
+            return;
+        };
+        let command = if line.trim().is_empty() {
+            match previous {
+                Some(command) => command,
+                None => continue,
+            }
+        } else {
+            match line.parse() {
+                Ok(command) => command,
+                Err(err) => {
+                    println!("Invalid command: {err}");
+                    continue;
+                }
+            }
+        };
+
+        if command == DebugCommand::Quit {
+            return;
+        }
+        print!("{}", debugger.execute(command));
+        previous = Some(command);
+    }
+}
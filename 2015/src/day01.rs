@@ -0,0 +1,47 @@
+use aoc_core::{parse::parse_chars, Answer, ParseError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_steps(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(steps: &[i32]) -> i32 {
+    steps.iter().sum()
+}
+
+/// Position of the first step that takes Santa into the basement
+fn part_two(steps: &[i32]) -> usize {
+    steps
+        .iter()
+        .scan(0, |floor, step| {
+            *floor += step;
+            Some(*floor)
+        })
+        .position(|floor| floor == -1)
+        .map_or(steps.len(), |index| index + 1)
+}
+
+fn parse_steps(data: &str) -> Result<Vec<i32>, ParseError> {
+    parse_chars(
+        data,
+        |chr| match chr {
+            '(' => Some(1),
+            ')' => Some(-1),
+            _ => None,
+        },
+        "( or )",
+    )
+}
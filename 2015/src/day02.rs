@@ -0,0 +1,70 @@
+use aoc_core::{
+    parse::{integer, parse_lines, parse_with},
+    Answer, ParseError, Solution,
+};
+use nom::{
+    character::complete::char,
+    sequence::{preceded, tuple},
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<(u32, u32, u32)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_presents(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(presents: &[(u32, u32, u32)]) -> u32 {
+    presents
+        .iter()
+        .map(|&(l, w, h)| {
+            let side1 = l * w;
+            let side2 = w * h;
+            let side3 = h * l;
+            let min_side = u32::min(u32::min(side1, side2), side3);
+
+            2 * (side1 + side2 + side3) + min_side
+        })
+        .sum()
+}
+
+fn part_two(presents: &[(u32, u32, u32)]) -> u32 {
+    presents
+        .iter()
+        .map(|&(l, w, h)| {
+            let perimeter1 = 2 * (l + w);
+            let perimeter2 = 2 * (w + h);
+            let perimeter3 = 2 * (h + l);
+            let min_perimeter = u32::min(u32::min(perimeter1, perimeter2), perimeter3);
+
+            let volume = l * w * h;
+
+            min_perimeter + volume
+        })
+        .sum()
+}
+
+fn parse_presents(data: &str) -> Result<Vec<(u32, u32, u32)>, ParseError> {
+    parse_lines(data, |line| {
+        parse_with(
+            line,
+            tuple((
+                integer,
+                preceded(char('x'), integer),
+                preceded(char('x'), integer),
+            )),
+            "dimensions such as \"2x3x4\"",
+        )
+    })
+}
@@ -0,0 +1,65 @@
+use aoc_core::{
+    parse::parse_chars,
+    vector::{Direction, Vec2},
+    Answer, ParseError, Solution,
+};
+use std::collections::HashSet;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Direction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_directions(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(directions: &[Direction]) -> usize {
+    let mut current = Vec2::<i32>::ZERO;
+    let mut coords = HashSet::new();
+    coords.insert(current);
+
+    for direction in directions {
+        current += direction.to_vec();
+        coords.insert(current);
+    }
+
+    coords.len()
+}
+
+fn part_two(directions: &[Direction]) -> usize {
+    let mut current = [Vec2::<i32>::ZERO; 2];
+    let mut coords = HashSet::new();
+    coords.insert(Vec2::ZERO);
+
+    for (index, direction) in directions.iter().enumerate() {
+        let person = index % 2;
+        current[person] += direction.to_vec();
+        coords.insert(current[person]);
+    }
+
+    coords.len()
+}
+
+fn parse_directions(data: &str) -> Result<Vec<Direction>, ParseError> {
+    parse_chars(
+        data,
+        |chr| match chr {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        },
+        "^, >, v or <",
+    )
+}
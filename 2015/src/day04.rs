@@ -0,0 +1,54 @@
+use aoc_core::{
+    parse::{all_chars, single_line},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_key(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(key: &str) -> u32 {
+    let mut number: u32 = 1;
+    loop {
+        let hash = md5::compute(format!("{key}{number}"));
+        if format!("{:?}", hash).chars().take(5).all(|x| x == '0') {
+            break;
+        }
+        number += 1;
+    }
+
+    number
+}
+
+fn part_two(key: &str) -> u32 {
+    let mut number: u32 = 1;
+    loop {
+        let hash = md5::compute(format!("{key}{number}"));
+        if format!("{:?}", hash).chars().take(6).all(|x| x == '0') {
+            break;
+        }
+        number += 1;
+    }
+
+    number
+}
+
+fn parse_key(data: &str) -> Result<&str, ParseError> {
+    let line = single_line(data, "secret key")?;
+    all_chars(line, |chr| chr.is_ascii_alphanumeric(), "letter or digit")
+        .map_err(|err| err.relative_to(data, line))
+}
@@ -1,18 +1,33 @@
+use aoc_core::{
+    parse::{all_chars, parse_lines},
+    Answer, ParseError, Solution,
+};
 use std::collections::HashSet;
 
-fn main() {
-    part_one();
-    part_two();
-}
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_strings(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
 
-fn part_one() {
-    let data = std::fs::read_to_string("data/05.txt").expect("Could not read datafile");
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
 
+fn part_one(strings: &[&str]) -> usize {
     let vowels = ['a', 'e', 'i', 'o', 'u'];
     let bad_combinations = ["ab", "cd", "pq", "xy"];
 
-    let nice = data
-        .lines()
+    strings
+        .iter()
         .filter(|line| {
             for bad_combination in bad_combinations {
                 if line.contains(bad_combination) {
@@ -35,16 +50,12 @@ fn part_one() {
 
             true
         })
-        .count();
-
-    println!("Part 1: {}", nice);
+        .count()
 }
 
-fn part_two() {
-    let data = std::fs::read_to_string("data/05.txt").expect("Could not read datafile");
-
-    let nice = data
-        .lines()
+fn part_two(strings: &[&str]) -> usize {
+    strings
+        .iter()
         .filter(|line| {
             let characters: Vec<char> = line.chars().collect();
 
@@ -66,7 +77,11 @@ fn part_two() {
 
             true
         })
-        .count();
+        .count()
+}
 
-    println!("Part 2: {}", nice);
+fn parse_strings(data: &str) -> Result<Vec<&str>, ParseError> {
+    parse_lines(data, |line| {
+        all_chars(line, |chr| chr.is_ascii_lowercase(), "lowercase letter")
+    })
 }
@@ -0,0 +1,150 @@
+use aoc_core::{
+    grid::{Coord, Grid},
+    parse::{integer, parse_lines, parse_with},
+    Answer, ParseError, Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::char,
+    combinator::value,
+    sequence::{preceded, separated_pair, tuple},
+    IResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    action: Action,
+    from: Coord,
+    to: Coord,
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(instructions: &[Instruction]) -> usize {
+    let mut lights = get_lights(instructions, false);
+
+    for instruction in instructions {
+        for coord in get_coords(instruction) {
+            lights[coord] = match instruction.action {
+                Action::TurnOn => true,
+                Action::TurnOff => false,
+                Action::Toggle => !lights[coord],
+            };
+        }
+    }
+
+    lights.values().filter(|&&light| light).count()
+}
+
+fn part_two(instructions: &[Instruction]) -> usize {
+    let mut lights = get_lights(instructions, 0usize);
+
+    for instruction in instructions {
+        for coord in get_coords(instruction) {
+            lights[coord] = match instruction.action {
+                Action::TurnOn => lights[coord] + 1,
+                Action::TurnOff => lights[coord].saturating_sub(1),
+                Action::Toggle => lights[coord] + 2,
+            };
+        }
+    }
+
+    lights.values().sum::<usize>()
+}
+
+/// Grid that is just large enough for every instruction
+fn get_lights<T: Clone>(instructions: &[Instruction], value: T) -> Grid<T> {
+    let (columns, rows) = instructions
+        .iter()
+        .fold((0, 0), |(columns, rows), instruction| {
+            (
+                columns.max(instruction.to.0 + 1),
+                rows.max(instruction.to.1 + 1),
+            )
+        });
+    Grid::new(columns, rows, value)
+}
+
+fn get_coords(instruction: &Instruction) -> impl Iterator<Item = Coord> {
+    let (from, to) = (instruction.from, instruction.to);
+    (from.1..=to.1).flat_map(move |y| (from.0..=to.0).map(move |x| (x, y)))
+}
+
+fn parse_instructions(data: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(data, |line| {
+        let (action, from, to) = parse_with(
+            line,
+            tuple((
+                parse_action,
+                preceded(char(' '), parse_coord),
+                preceded(tag(" through "), parse_coord),
+            )),
+            "instruction such as \"toggle 0,0 through 999,0\"",
+        )?;
+        // Ranges run from the lowest to the highest coordinate
+        let (from, to) = (
+            (from.0.min(to.0), from.1.min(to.1)),
+            (from.0.max(to.0), from.1.max(to.1)),
+        );
+        Ok(Instruction { action, from, to })
+    })
+}
+
+fn parse_action(input: &str) -> IResult<&str, Action> {
+    alt((
+        value(Action::TurnOn, tag("turn on")),
+        value(Action::TurnOff, tag("turn off")),
+        value(Action::Toggle, tag("toggle")),
+    ))(input)
+}
+
+fn parse_coord(input: &str) -> IResult<&str, Coord> {
+    separated_pair(integer, char(','), integer)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let err =
+            parse_instructions("toggle 0,0 through 9,9\nturn up 1,1 through 2,2").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "turn"));
+
+        let err = parse_instructions("turn on 0,0 through 9,x").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (23, "x"));
+    }
+
+    #[test]
+    fn test_grid_size() {
+        let instructions =
+            parse_instructions("turn on 2,3 through 0,1\ntoggle 1,1 through 1,1").unwrap();
+        assert_eq!(part_one(&instructions), 8);
+        assert_eq!(part_two(&instructions), 11);
+    }
+}
@@ -0,0 +1,679 @@
+use aoc_core::{parse::parse_lines, Answer, ParseError, Solution};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr},
+    str::FromStr,
+};
+
+type Var = String;
+
+/// Unsigned integer that carries the signal on a wire
+pub trait Word:
+    Copy
+    + Display
+    + FromStr
+    + Not<Output = Self>
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+{
+    const BITS: u32;
+}
+
+macro_rules! impl_word {
+    ($($t:ty),*) => {
+        $(
+            impl Word for $t {
+                const BITS: u32 = <$t>::BITS;
+            }
+        )*
+    };
+}
+
+impl_word!(u8, u16, u32, u64, u128);
+
+#[derive(PartialEq, Debug)]
+pub enum Value<W = u16> {
+    Variable(Var),
+    Number(W),
+}
+
+#[derive(Debug)]
+pub enum Instruction<W = u16> {
+    Set(Value<W>),
+    Not(Value<W>),
+    And(Value<W>, Value<W>),
+    Or(Value<W>, Value<W>),
+    Xor(Value<W>, Value<W>),
+    Nand(Value<W>, Value<W>),
+    LShift(Value<W>, u32),
+    RShift(Value<W>, u32),
+}
+
+impl<W: Display> Display for Value<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Variable(wire) => write!(f, "{wire}"),
+            Value::Number(number) => write!(f, "{number}"),
+        }
+    }
+}
+
+impl<W: Display> Display for Instruction<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Set(value) => write!(f, "{value}"),
+            Instruction::Not(value) => write!(f, "NOT {value}"),
+            Instruction::And(value1, value2) => write!(f, "{value1} AND {value2}"),
+            Instruction::Or(value1, value2) => write!(f, "{value1} OR {value2}"),
+            Instruction::Xor(value1, value2) => write!(f, "{value1} XOR {value2}"),
+            Instruction::Nand(value1, value2) => write!(f, "{value1} NAND {value2}"),
+            Instruction::LShift(value, shift) => write!(f, "{value} LSHIFT {shift}"),
+            Instruction::RShift(value, shift) => write!(f, "{value} RSHIFT {shift}"),
+        }
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<(Var, Instruction)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(instructions: &[(Var, Instruction)]) -> Result<u16, CircuitError> {
+    Circuit::new(instructions)?.signal("a")
+}
+
+fn part_two(instructions: &[(Var, Instruction)]) -> Result<u16, CircuitError> {
+    let mut circuit = Circuit::new(instructions)?;
+    let a = circuit.signal("a")?;
+
+    // Only the wires that depend on b are evaluated again
+    circuit.override_signal("b", a)?;
+    circuit.signal("a")
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CircuitError {
+    /// Wires that each read the next one, with the last one reading the first
+    Cycle(Vec<Var>),
+    /// Wire that is read but never given a signal
+    Dangling(Var),
+    /// Wire that is the output of more than one instruction
+    Duplicate(Var),
+    UnknownWire(Var),
+}
+
+impl Display for CircuitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CircuitError::Cycle(wires) => {
+                write!(
+                    f,
+                    "Wires form a cycle: {} -> {}",
+                    wires.join(" -> "),
+                    wires[0]
+                )
+            }
+            CircuitError::Dangling(wire) => write!(f, "Wire {wire} is read but never driven"),
+            CircuitError::Duplicate(wire) => write!(f, "Wire {wire} is driven more than once"),
+            CircuitError::UnknownWire(wire) => write!(f, "Wire {wire} does not exist"),
+        }
+    }
+}
+
+impl std::error::Error for CircuitError {}
+
+/// Circuit whose wires are evaluated when they are needed, keeping every signal until an
+/// override changes one of the wires it depends on
+struct Circuit<'a, W> {
+    wires: &'a [(Var, Instruction<W>)],
+    indices: HashMap<&'a str, usize>,
+    /// Wires that each wire reads
+    inputs: Vec<Vec<usize>>,
+    /// Wires that read each wire
+    readers: Vec<Vec<usize>>,
+    signals: Vec<Option<W>>,
+    overrides: Vec<Option<W>>,
+}
+
+impl<'a, W: Word> Circuit<'a, W> {
+    fn new(instructions: &'a [(Var, Instruction<W>)]) -> Result<Self, CircuitError> {
+        let mut indices = HashMap::new();
+        for (index, (wire, _instruction)) in instructions.iter().enumerate() {
+            if indices.insert(wire.as_str(), index).is_some() {
+                return Err(CircuitError::Duplicate(wire.clone()));
+            }
+        }
+
+        let mut inputs = vec![Vec::new(); instructions.len()];
+        let mut readers = vec![Vec::new(); instructions.len()];
+        for (index, (_wire, instruction)) in instructions.iter().enumerate() {
+            for input in get_inputs(instruction) {
+                let &input_index = indices
+                    .get(input.as_str())
+                    .ok_or_else(|| CircuitError::Dangling(input.clone()))?;
+                inputs[index].push(input_index);
+                readers[input_index].push(index);
+            }
+        }
+
+        // Evaluating wires on demand relies on the circuit having no cycles
+        find_cycle(&inputs).map_err(|cycle| {
+            let wires = cycle
+                .into_iter()
+                .map(|index| instructions[index].0.clone())
+                .collect();
+            CircuitError::Cycle(wires)
+        })?;
+
+        Ok(Circuit {
+            wires: instructions,
+            indices,
+            inputs,
+            readers,
+            signals: vec![None; instructions.len()],
+            overrides: vec![None; instructions.len()],
+        })
+    }
+
+    fn get_index(&self, wire: &str) -> Result<usize, CircuitError> {
+        self.indices
+            .get(wire)
+            .copied()
+            .ok_or_else(|| CircuitError::UnknownWire(wire.to_string()))
+    }
+
+    /// Signal on `wire`, evaluating only the wires it depends on that have not been evaluated yet
+    fn signal(&mut self, wire: &str) -> Result<W, CircuitError> {
+        let index = self.get_index(wire)?;
+
+        // A wire stays on the stack until all of its inputs have a signal
+        let mut stack = vec![index];
+        while let Some(&wire) = stack.last() {
+            if self.signals[wire].is_some() {
+                stack.pop();
+                continue;
+            }
+            if let Some(signal) = self.overrides[wire] {
+                self.signals[wire] = Some(signal);
+                stack.pop();
+                continue;
+            }
+
+            let pending = stack.len();
+            let signals = &self.signals;
+            stack.extend(
+                self.inputs[wire]
+                    .iter()
+                    .filter(|&&input| signals[input].is_none()),
+            );
+            if stack.len() == pending {
+                self.signals[wire] = Some(self.evaluate(&self.wires[wire].1));
+                stack.pop();
+            }
+        }
+
+        Ok(self.signals[index].expect("Requested wire has been evaluated"))
+    }
+
+    /// Gives `wire` a fixed signal instead of the one from its gate.
+    /// Only the wires that depend on it are evaluated again.
+    fn override_signal(&mut self, wire: &str, signal: W) -> Result<(), CircuitError> {
+        let index = self.get_index(wire)?;
+        self.overrides[index] = Some(signal);
+
+        let mut stack = vec![index];
+        while let Some(wire) = stack.pop() {
+            if self.signals[wire].take().is_some() {
+                stack.extend(self.readers[wire].iter().copied());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the tree of wires that feed `wire`, which has to be evaluated, with their signals.
+    /// Wires that were already written are not expanded again.
+    fn write_trace(
+        &self,
+        wire: usize,
+        prefix: &str,
+        child_prefix: &str,
+        written: &mut [bool],
+        output: &mut String,
+    ) {
+        let (name, instruction) = &self.wires[wire];
+        let signal = self.signals[wire].expect("Traced wires have been evaluated");
+
+        output.push_str(&format!("{prefix}{name} = {signal}"));
+        if written[wire] {
+            output.push_str(" (see above)\n");
+            return;
+        }
+        written[wire] = true;
+
+        output.push_str(&format!(": {instruction}\n"));
+
+        let inputs = &self.inputs[wire];
+        for (index, &input) in inputs.iter().enumerate() {
+            let (branch, indent) = if index + 1 == inputs.len() {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            self.write_trace(
+                input,
+                &format!("{child_prefix}{branch}"),
+                &format!("{child_prefix}{indent}"),
+                written,
+                output,
+            );
+        }
+    }
+
+    /// Output of a gate whose inputs have all been evaluated
+    fn evaluate(&self, instruction: &Instruction<W>) -> W {
+        let value = |value: &Value<W>| match value {
+            Value::Variable(wire) => self.signals[self.indices[wire.as_str()]]
+                .expect("Inputs are evaluated before the wires that read them"),
+            Value::Number(number) => *number,
+        };
+
+        match instruction {
+            Instruction::Set(input) => value(input),
+            Instruction::Not(input) => !value(input),
+            Instruction::And(input1, input2) => value(input1) & value(input2),
+            Instruction::Or(input1, input2) => value(input1) | value(input2),
+            Instruction::Xor(input1, input2) => value(input1) ^ value(input2),
+            Instruction::Nand(input1, input2) => !(value(input1) & value(input2)),
+            Instruction::LShift(input, shift) => value(input) << *shift,
+            Instruction::RShift(input, shift) => value(input) >> *shift,
+        }
+    }
+}
+
+/// Values that are read by an instruction
+fn get_values<W>(instruction: &Instruction<W>) -> impl Iterator<Item = &Value<W>> {
+    let values = match instruction {
+        Instruction::Set(value)
+        | Instruction::Not(value)
+        | Instruction::LShift(value, _)
+        | Instruction::RShift(value, _) => [Some(value), None],
+        Instruction::And(value1, value2)
+        | Instruction::Or(value1, value2)
+        | Instruction::Xor(value1, value2)
+        | Instruction::Nand(value1, value2) => [Some(value1), Some(value2)],
+    };
+    values.into_iter().flatten()
+}
+
+/// Wires that are read by an instruction
+fn get_inputs<W>(instruction: &Instruction<W>) -> impl Iterator<Item = &Var> {
+    get_values(instruction).filter_map(|value| match value {
+        Value::Variable(wire) => Some(wire),
+        Value::Number(_) => None,
+    })
+}
+
+/// Expression tree that feeds `wire`, with the signal on every wire in it
+pub fn trace<W: Word>(
+    instructions: &[(Var, Instruction<W>)],
+    wire: &str,
+) -> Result<String, CircuitError> {
+    let mut circuit = Circuit::new(instructions)?;
+    circuit.signal(wire)?;
+
+    let mut output = String::new();
+    let mut written = vec![false; instructions.len()];
+    circuit.write_trace(circuit.get_index(wire)?, "", "", &mut written, &mut output);
+    Ok(output)
+}
+
+/// Wires that no gate reads, such as the outputs of the circuit
+pub fn get_unread_wires<W>(instructions: &[(Var, Instruction<W>)]) -> Vec<&Var> {
+    let read = instructions
+        .iter()
+        .flat_map(|(_wire, instruction)| get_inputs(instruction))
+        .collect::<HashSet<_>>();
+
+    instructions
+        .iter()
+        .map(|(wire, _instruction)| wire)
+        .filter(|wire| !read.contains(wire))
+        .collect()
+}
+
+/// Graphviz graph of the circuit, with a box for every gate between the wires it connects
+pub fn to_dot<W: Display>(instructions: &[(Var, Instruction<W>)]) -> String {
+    let mut dot = String::from("digraph circuit {\n    rankdir=LR;\n");
+
+    for (wire, instruction) in instructions {
+        if let Instruction::Set(Value::Variable(input)) = instruction {
+            dot.push_str(&format!("    \"{input}\" -> \"{wire}\";\n"));
+            continue;
+        }
+
+        let gate = format!("{wire} gate");
+        dot.push_str(&format!(
+            "    \"{gate}\" [label=\"{instruction}\", shape=box];\n"
+        ));
+        for input in get_inputs(instruction) {
+            dot.push_str(&format!("    \"{input}\" -> \"{gate}\";\n"));
+        }
+        dot.push_str(&format!("    \"{gate}\" -> \"{wire}\";\n"));
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Writes the instructions back out in the syntax of the input
+pub fn to_source<W: Display>(instructions: &[(Var, Instruction<W>)]) -> String {
+    instructions
+        .iter()
+        .map(|(wire, instruction)| format!("{instruction} -> {wire}\n"))
+        .collect()
+}
+
+/// Returns the nodes of a cycle through the inputs, if there is one
+fn find_cycle(inputs: &[Vec<usize>]) -> Result<(), Vec<usize>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Mark {
+        Unvisited,
+        InProgress,
+        Done,
+    }
+
+    let mut marks = vec![Mark::Unvisited; inputs.len()];
+
+    for start in 0..inputs.len() {
+        if marks[start] != Mark::Unvisited {
+            continue;
+        }
+
+        // Path of nodes being visited, with the number of inputs handled for each
+        let mut path = vec![(start, 0)];
+        marks[start] = Mark::InProgress;
+
+        while let Some((node, handled)) = path.last_mut() {
+            let node = *node;
+            let Some(&input) = inputs[node].get(*handled) else {
+                marks[node] = Mark::Done;
+                path.pop();
+                continue;
+            };
+            *handled += 1;
+
+            match marks[input] {
+                Mark::Unvisited => {
+                    marks[input] = Mark::InProgress;
+                    path.push((input, 0));
+                }
+                Mark::InProgress => {
+                    let cycle_start = path
+                        .iter()
+                        .position(|&(node, _)| node == input)
+                        .expect("Nodes in progress are on the path");
+                    return Err(path[cycle_start..].iter().map(|&(node, _)| node).collect());
+                }
+                Mark::Done => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn parse_instructions<W: Word>(data: &str) -> Result<Vec<(Var, Instruction<W>)>, ParseError> {
+    parse_lines(data, parse_instruction)
+}
+
+fn parse_instruction<W: Word>(line: &str) -> Result<(Var, Instruction<W>), ParseError> {
+    let (input, output) = line
+        .split_once(" -> ")
+        .ok_or_else(|| ParseError::missing(line, "\" -> \" followed by a wire"))?;
+    let output = parse_wire(line, output)?;
+
+    let words = input.split_whitespace().collect::<Vec<_>>();
+    let instruction = match words.as_slice() {
+        [value] => Instruction::Set(parse_value(line, value)?),
+        ["NOT", value] => Instruction::Not(parse_value(line, value)?),
+        [value, "LSHIFT", shift] => {
+            Instruction::LShift(parse_value(line, value)?, parse_shift::<W>(line, shift)?)
+        }
+        [value, "RSHIFT", shift] => {
+            Instruction::RShift(parse_value(line, value)?, parse_shift::<W>(line, shift)?)
+        }
+        [value1, gate, value2] => {
+            let gate = match *gate {
+                "AND" => Instruction::And,
+                "OR" => Instruction::Or,
+                "XOR" => Instruction::Xor,
+                "NAND" => Instruction::Nand,
+                _ => {
+                    return Err(ParseError::new(
+                        line,
+                        gate,
+                        "AND, OR, XOR, NAND, LSHIFT or RSHIFT",
+                    ))
+                }
+            };
+            gate(parse_value(line, value1)?, parse_value(line, value2)?)
+        }
+        [] => return Err(ParseError::new(line, input, "wire or number")),
+        _ => return Err(ParseError::new(line, input, "gate expression")),
+    };
+
+    Ok((output, instruction))
+}
+
+fn parse_value<W: Word>(line: &str, word: &str) -> Result<Value<W>, ParseError> {
+    if word.starts_with(|c: char| c.is_ascii_digit()) {
+        word.parse()
+            .map(Value::Number)
+            .map_err(|_| ParseError::new(line, word, format!("number of at most {} bits", W::BITS)))
+    } else {
+        parse_wire(line, word).map(Value::Variable)
+    }
+}
+
+fn parse_wire(line: &str, word: &str) -> Result<Var, ParseError> {
+    if !word.is_empty() && word.chars().all(|c| c.is_ascii_lowercase()) {
+        Ok(word.to_owned())
+    } else {
+        Err(ParseError::new(line, word, "wire"))
+    }
+}
+
+fn parse_shift<W: Word>(line: &str, word: &str) -> Result<u32, ParseError> {
+    word.parse::<u32>()
+        .ok()
+        .filter(|&shift| shift < W::BITS)
+        .ok_or_else(|| ParseError::new(line, word, format!("shift amount below {}", W::BITS)))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let data = "123 -> x\nx AND y -> d\nNOT x -> h\ny RSHIFT 2 -> g";
+        let instructions = parse_instructions::<u16>(data).unwrap();
+        assert_eq!(instructions.len(), 4);
+        assert_eq!(instructions[0].0, "x");
+        assert!(matches!(
+            instructions[3].1,
+            Instruction::RShift(Value::Variable(_), 2)
+        ));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_instructions::<u16>("123 -> x\nx NOR y -> d").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.text, "NOR");
+
+        let err = parse_instructions::<u16>("y LSHIFT z -> f").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 10));
+        assert_eq!(err.expected, "shift amount below 16");
+
+        let err = parse_instructions::<u8>("x LSHIFT 8 -> f").unwrap_err();
+        assert_eq!(err.expected, "shift amount below 8");
+
+        let err = parse_instructions::<u8>("256 -> x").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (1, "256"));
+        assert_eq!(err.expected, "number of at most 8 bits");
+
+        let err = parse_instructions::<u16>("NOT x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 6));
+        assert!(err.text.is_empty());
+    }
+
+    #[test]
+    fn test_override() {
+        let instructions =
+            parse_instructions::<u16>("123 -> x\nx AND y -> d\n456 -> y\nNOT d -> h").unwrap();
+        let mut circuit = Circuit::new(&instructions).unwrap();
+        assert_eq!(circuit.signal("h"), Ok(!72));
+
+        circuit.override_signal("y", 0xffff).unwrap();
+        assert_eq!(circuit.signal("d"), Ok(123));
+        assert_eq!(circuit.signal("h"), Ok(!123));
+        assert_eq!(circuit.signal("y"), Ok(0xffff));
+        assert_eq!(
+            circuit.override_signal("z", 1),
+            Err(CircuitError::UnknownWire("z".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_circuit_errors() {
+        let instructions =
+            parse_instructions::<u16>("1 -> a\na AND c -> b\nb -> c\nc -> d").unwrap();
+        let err = Circuit::new(&instructions).err().unwrap();
+        assert_eq!(
+            err,
+            CircuitError::Cycle(vec!["b".to_string(), "c".to_string()])
+        );
+        assert_eq!(err.to_string(), "Wires form a cycle: b -> c -> b");
+
+        let instructions = parse_instructions::<u16>("1 -> a\na OR q -> b").unwrap();
+        let err = Circuit::new(&instructions).err().unwrap();
+        assert_eq!(err, CircuitError::Dangling("q".to_string()));
+
+        let instructions = parse_instructions::<u16>("1 -> a\n2 -> b\nb -> a").unwrap();
+        let err = Circuit::new(&instructions).err().unwrap();
+        assert_eq!(err, CircuitError::Duplicate("a".to_string()));
+        assert_eq!(err.to_string(), "Wire a is driven more than once");
+
+        let instructions = parse_instructions::<u16>("1 -> b").unwrap();
+        assert_eq!(
+            part_one(&instructions),
+            Err(CircuitError::UnknownWire("a".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_signal_dependencies() {
+        let instructions =
+            parse_instructions::<u16>("1 -> x\n2 -> y\nx -> a\ny -> b\na OR b -> c").unwrap();
+        let mut circuit = Circuit::new(&instructions).unwrap();
+        assert_eq!(circuit.signal("a"), Ok(1));
+        assert_eq!(circuit.signals, [Some(1), None, Some(1), None, None]);
+
+        assert_eq!(circuit.signal("c"), Ok(3));
+        assert!(circuit.signals.iter().all(Option::is_some));
+    }
+
+    #[test]
+    fn test_trace() {
+        let instructions = parse_instructions::<u16>(&read_example(2015, 7, 1)).unwrap();
+        let expected = "\
+a = 510: b OR f
+├── b = 114: g
+│   └── g = 114: y RSHIFT 2
+│       └── y = 456: 456
+└── f = 492: x LSHIFT 2
+    └── x = 123: 123
+";
+        assert_eq!(trace(&instructions, "a").unwrap(), expected);
+
+        let instructions = parse_instructions::<u16>("3 -> x\nx AND x -> y").unwrap();
+        assert_eq!(
+            trace(&instructions, "y").unwrap(),
+            "y = 3: x AND x\n├── x = 3: 3\n└── x = 3 (see above)\n"
+        );
+        assert_eq!(
+            trace(&instructions, "z"),
+            Err(CircuitError::UnknownWire("z".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_unread_wires() {
+        let instructions = parse_instructions::<u16>(&read_example(2015, 7, 1)).unwrap();
+        assert_eq!(get_unread_wires(&instructions), ["d", "e", "h", "i", "a"]);
+    }
+
+    #[test]
+    fn test_dot() {
+        let instructions = parse_instructions::<u16>("123 -> x\nx -> b\nb AND 1 -> a").unwrap();
+        let expected = "\
+digraph circuit {
+    rankdir=LR;
+    \"x gate\" [label=\"123\", shape=box];
+    \"x gate\" -> \"x\";
+    \"x\" -> \"b\";
+    \"a gate\" [label=\"b AND 1\", shape=box];
+    \"b\" -> \"a gate\";
+    \"a gate\" -> \"a\";
+}
+";
+        assert_eq!(to_dot(&instructions), expected);
+    }
+
+    #[test]
+    fn test_word_widths() {
+        let data = "200 -> x\n100 -> y\nx XOR y -> a\nx NAND y -> b\nNOT x -> c\nx LSHIFT 1 -> d";
+        let instructions = parse_instructions::<u8>(data).unwrap();
+        let mut circuit = Circuit::new(&instructions).unwrap();
+        assert_eq!(circuit.signal("a"), Ok(172));
+        assert_eq!(circuit.signal("b"), Ok(191));
+        assert_eq!(circuit.signal("c"), Ok(55));
+        assert_eq!(circuit.signal("d"), Ok(144));
+
+        let instructions = parse_instructions::<u64>(data).unwrap();
+        let mut circuit = Circuit::new(&instructions).unwrap();
+        assert_eq!(circuit.signal("c"), Ok(!200));
+        assert_eq!(circuit.signal("d"), Ok(400));
+    }
+
+    #[test]
+    fn test_to_source() {
+        let data = read_example(2015, 7, 1);
+        let instructions = parse_instructions::<u16>(&data).unwrap();
+        assert_eq!(to_source(&instructions), data);
+
+        let data = "1 -> x\nx NAND 3 -> y\ny XOR x -> z\n";
+        assert_eq!(to_source(&parse_instructions::<u32>(data).unwrap()), data);
+    }
+}
@@ -0,0 +1,120 @@
+use aoc_core::{parse::parse_lines, Answer, ParseError, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Vec<(&'a str, String)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_strings(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(strings: &[(&str, String)]) -> usize {
+    strings
+        .iter()
+        .map(|(code_string, memory_string)| {
+            code_string.chars().count() - memory_string.chars().count()
+        })
+        .sum()
+}
+
+fn part_two(strings: &[(&str, String)]) -> usize {
+    strings
+        .iter()
+        .map(|&(line, _)| {
+            let code_string = line;
+            let mut encoded_string = String::new();
+            encoded_string.push('"');
+
+            let chars = line.chars();
+
+	    for character in chars {
+                match character {
+                    '\\' => encoded_string.push_str("\\\\"),
+                    '"' => encoded_string.push_str("\\\""),
+                    _ => encoded_string.push(character),
+                }
+            }
+
+            encoded_string.push('"');
+
+            encoded_string.chars().count() - code_string.chars().count()
+        })
+        .sum()
+}
+
+/// Every line as written in the code, together with the string it stands for
+fn parse_strings(data: &str) -> Result<Vec<(&str, String)>, ParseError> {
+    parse_lines(data, |line| Ok((line, parse_string(line)?)))
+}
+
+fn parse_string(line: &str) -> Result<String, ParseError> {
+    let mut chars = line.char_indices();
+    if !matches!(chars.next(), Some((_, '"'))) {
+        return Err(ParseError::new(
+            line,
+            line.get(..1).unwrap_or(line),
+            "opening quote",
+        ));
+    }
+
+    let mut memory_string = String::new();
+    loop {
+        match chars.next() {
+            Some((_, '"')) => break,
+            Some((index, '\\')) => match chars.next() {
+                Some((_, escaped_char @ ('\\' | '"'))) => memory_string.push(escaped_char),
+                Some((_, 'x')) => {
+                    let hexadecimal = line
+                        .get(index + 2..index + 4)
+                        .filter(|hex| hex.chars().all(|chr| chr.is_ascii_hexdigit()))
+                        .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                        .ok_or_else(|| {
+                            let escape = line.get(index..index + 4).unwrap_or(&line[index..]);
+                            ParseError::new(line, escape, "two hexadecimal digits after \\x")
+                        })?;
+                    memory_string.push(char::from(hexadecimal));
+                    chars.nth(1);
+                }
+                Some((escaped_index, escaped_char)) => {
+                    let escape = &line[index..escaped_index + escaped_char.len_utf8()];
+                    return Err(ParseError::new(line, escape, "\\\\, \\\" or \\x"));
+                }
+                None => return Err(ParseError::missing(line, "escaped character")),
+            },
+            Some((_, chr)) => memory_string.push(chr),
+            None => return Err(ParseError::missing(line, "closing quote")),
+        }
+    }
+
+    match chars.next() {
+        Some((index, _)) => Err(ParseError::new(line, &line[index..], "end of line")),
+        None => Ok(memory_string),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_strings("\"abc\"\n\"a\\qb\"").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "\\q"));
+
+        let err = parse_strings("\"\\x2g\"").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (2, "\\x2g"));
+
+        let err = parse_strings("\"abc").unwrap_err();
+        assert_eq!(err.expected, "closing quote");
+    }
+}
@@ -0,0 +1,88 @@
+use aoc_core::{
+    parse::{integer, parse_lines, parse_with, word},
+    Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    sequence::{preceded, tuple},
+};
+use std::collections::{HashMap, HashSet};
+
+/// Every location and the distance between every pair of locations, in both directions
+type Distances<'a> = (Vec<&'a str>, HashMap<(&'a str, &'a str), u32>);
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Distances<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_distances(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one((locations, paths): &Distances) -> u32 {
+    locations
+        .iter()
+        .permutations(locations.len())
+        .map(|combination| {
+            combination
+                .windows(2)
+                .map(|pair| {
+                    paths
+                        .get(&(*pair[0], *pair[1]))
+                        .cloned()
+                        .unwrap_or(u32::MAX / locations.len() as u32)
+                })
+                .sum::<u32>()
+        })
+        .min()
+        .expect("Could not find a minimum path")
+}
+
+fn part_two((locations, paths): &Distances) -> u32 {
+    locations
+        .iter()
+        .permutations(locations.len())
+        .map(|combination| {
+            combination
+                .windows(2)
+                .map(|pair| paths.get(&(*pair[0], *pair[1])).cloned().unwrap_or(0))
+                .sum::<u32>()
+        })
+        .max()
+        .expect("Could not find a minimum path")
+}
+
+fn parse_distances(data: &str) -> Result<Distances<'_>, ParseError> {
+    let mut locations = HashSet::new();
+    let mut paths = HashMap::new();
+
+    for (location1, location2, distance) in parse_lines(data, |line| {
+        parse_with(
+            line,
+            tuple((
+                word,
+                preceded(tag(" to "), word),
+                preceded(tag(" = "), integer),
+            )),
+            "distance such as \"London to Dublin = 464\"",
+        )
+    })? {
+        locations.insert(location1);
+        locations.insert(location2);
+        paths.insert((location1, location2), distance);
+        paths.insert((location2, location1), distance);
+    }
+
+    Ok((Vec::from_iter(locations), paths))
+}
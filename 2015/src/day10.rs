@@ -0,0 +1,69 @@
+use aoc_core::{
+    parse::{all_chars, single_line},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_digits(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(data: &str) -> usize {
+    apply_look_say(data, 40).len()
+}
+
+fn part_two(data: &str) -> usize {
+    apply_look_say(data, 50).len()
+}
+
+fn apply_look_say(data: &str, iterations: usize) -> String {
+    let mut input = data.to_owned();
+
+    for _ in 1..=iterations {
+        input = look_say(input);
+    }
+
+    input
+}
+
+fn look_say(input: String) -> String {
+    let mut characters = input.chars();
+    let mut last_char: char = characters.next().expect("Input was empty");
+
+    let mut output: String = String::new();
+    let mut counter: u32 = 1;
+
+    characters.for_each(|character| {
+        if character == last_char {
+            counter += 1;
+        } else {
+            output.push_str(&counter.to_string());
+            output.push(last_char);
+            last_char = character;
+            counter = 1;
+        }
+    });
+
+    output.push_str(&counter.to_string());
+    output.push(last_char);
+
+    output
+}
+
+fn parse_digits(data: &str) -> Result<&str, ParseError> {
+    let line = single_line(data, "digits")?;
+    all_chars(line, |chr| chr.is_ascii_digit(), "digit").map_err(|err| err.relative_to(data, line))
+}
@@ -0,0 +1,139 @@
+use aoc_core::{
+    parse::{all_chars, single_line},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_password(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+/// A straight of three letters and two different pairs need at least five letters
+const MIN_LENGTH: usize = 5;
+
+fn part_one(password: &[u8]) -> Option<String> {
+    let base = b'a';
+    let (_password, password1) = find_next(password.to_vec(), base)?;
+    Some(password1)
+}
+
+fn part_two(password: &[u8]) -> Option<String> {
+    let base = b'a';
+    let (password, _password1) = find_next(password.to_vec(), base)?;
+    let (_password, password2) = find_next(password, base)?;
+    Some(password2)
+}
+
+fn parse_password(data: &str) -> Result<Vec<u8>, ParseError> {
+    let line = single_line(data, "password")?;
+    let password = all_chars(line, |chr| chr.is_ascii_lowercase(), "lowercase letter")
+        .map_err(|err| err.relative_to(data, line))?;
+    if password.len() < MIN_LENGTH {
+        return Err(ParseError::new(
+            data,
+            line,
+            format!("password of at least {MIN_LENGTH} letters"),
+        ));
+    }
+    Ok(password.bytes().collect())
+}
+
+/// Next valid password, or `None` if the password runs out of letters first
+fn find_next(mut password: Vec<u8>, base: u8) -> Option<(Vec<u8>, String)> {
+    loop {
+        password = increment_password(password, base)?;
+
+        if is_valid(password.clone()) {
+            break;
+        }
+    }
+
+    Some((
+        password.clone(),
+        password
+            .iter()
+            .map(|&number| number as char)
+            .collect::<String>(),
+    ))
+}
+
+fn increment_password(mut password: Vec<u8>, base: u8) -> Option<Vec<u8>> {
+    let mut increment_index = password.len().checked_sub(1)?;
+
+    loop {
+        if password[increment_index] == base + 25 {
+            password[increment_index] = base;
+            increment_index = increment_index.checked_sub(1)?;
+        } else {
+            password[increment_index] += 1;
+            break;
+        }
+    }
+
+    Some(password)
+}
+
+fn is_valid(password: Vec<u8>) -> bool {
+    let forbidden_chars = ['i', 'o', 'l'].map(|character| character as u8);
+    for forbidden_char in forbidden_chars {
+        if password.contains(&forbidden_char) {
+            return false;
+        }
+    }
+    if !password
+        .windows(3)
+        .any(|window| window[0] + 1 == window[1] && window[1] + 1 == window[2])
+    {
+        return false;
+    }
+
+    let pair_count = password
+        .windows(2)
+        .filter(|window| window[0] == window[1])
+        .count();
+
+    let triplet_count = password
+        .windows(3)
+        .filter(|window| window[0] == window[1] && window[1] == window[2])
+        .count();
+
+    if pair_count - triplet_count <= 1 {
+        return false;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_password("").unwrap_err();
+        assert_eq!(err.expected, "password");
+
+        let err = parse_password("x\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 1, "x"));
+        assert_eq!(err.expected, "password of at least 5 letters");
+    }
+
+    #[test]
+    fn test_last_password() {
+        assert_eq!(part_one(b"abcdefgh"), Some("abcdffaa".to_string()));
+        assert_eq!(part_one(b"zzzzz"), None);
+    }
+}
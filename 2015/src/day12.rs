@@ -0,0 +1,374 @@
+use aoc_core::{Answer, ParseError, Solution};
+
+type Key = String;
+type Pair = (Key, Token);
+
+#[derive(Debug, PartialEq)]
+pub enum Token {
+    String(String),
+    Number(isize),
+    Array(Vec<Token>),
+    Object(Vec<Pair>),
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Token;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        tokenize(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(tokens: &Token) -> isize {
+    let mut token_stack: Vec<&Token> = vec![tokens];
+
+    let mut total: isize = 0;
+
+    while let Some(token) = token_stack.pop() {
+        match token {
+            Token::Number(value) => total += value,
+            Token::Array(values) => {
+                for value in values {
+                    token_stack.push(value);
+                }
+            }
+            Token::Object(pairs) => {
+                for (_key, value) in pairs {
+                    token_stack.push(value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    total
+}
+
+fn part_two(tokens: &Token) -> isize {
+    let mut token_stack: Vec<&Token> = vec![tokens];
+
+    let mut total: isize = 0;
+
+    while let Some(token) = token_stack.pop() {
+        match token {
+            Token::Number(value) => total += value,
+            Token::Array(values) => {
+                for value in values {
+                    token_stack.push(value);
+                }
+            }
+            Token::Object(pairs)
+                if !pairs
+                    .iter()
+                    .any(|(_key, value)| value == &Token::String("red".to_string())) =>
+            {
+                for (_key, value) in pairs {
+                    token_stack.push(value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    total
+}
+
+fn tokenize(input: &str) -> Result<Token, ParseError> {
+    let mut tokenizer = Tokenizer {
+        source: input,
+        rest: input,
+    };
+
+    let token = tokenizer.value()?;
+    tokenizer.skip_whitespaces();
+    if tokenizer.rest.is_empty() {
+        Ok(token)
+    } else {
+        Err(tokenizer.unexpected("end of input"))
+    }
+}
+
+/// Reads JSON from the front of `rest`, keeping `source` around to position errors
+struct Tokenizer<'a> {
+    source: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn value(&mut self) -> Result<Token, ParseError> {
+        self.skip_whitespaces();
+        match self.rest.chars().next() {
+            Some('"') => self.string().map(Token::String),
+            Some('-' | '0'..='9') => self.number().map(Token::Number),
+            Some('[') => {
+                self.rest = &self.rest[1..];
+                self.sequence(']', Self::value).map(Token::Array)
+            }
+            Some('{') => {
+                self.rest = &self.rest[1..];
+                self.sequence('}', Self::pair).map(Token::Object)
+            }
+            _ => Err(self.unexpected("JSON value")),
+        }
+    }
+
+    /// Comma separated items up to and including `close`, the opening character is already consumed
+    fn sequence<T>(
+        &mut self,
+        close: char,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = Vec::new();
+        self.skip_whitespaces();
+        if self.eat(close) {
+            return Ok(items);
+        }
+
+        loop {
+            items.push(item(self)?);
+            self.skip_whitespaces();
+            if self.eat(close) {
+                return Ok(items);
+            } else if !self.eat(',') {
+                return Err(self.unexpected(&format!("',' or '{close}'")));
+            }
+        }
+    }
+
+    fn pair(&mut self) -> Result<Pair, ParseError> {
+        self.skip_whitespaces();
+        if !self.rest.starts_with('"') {
+            return Err(self.unexpected("object key"));
+        }
+        let key = self.string()?;
+
+        self.skip_whitespaces();
+        if !self.eat(':') {
+            return Err(self.unexpected("':'"));
+        }
+        Ok((key, self.value()?))
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        let (value, rest) = self.rest[1..]
+            .split_once('"')
+            .ok_or_else(|| ParseError::missing(self.source, "closing quote"))?;
+        self.rest = rest;
+        Ok(value.to_string())
+    }
+
+    fn number(&mut self) -> Result<isize, ParseError> {
+        let length = self.rest[1..]
+            .find(|chr: char| !chr.is_ascii_digit())
+            .map_or(self.rest.len(), |index| index + 1);
+        let (number, rest) = self.rest.split_at(length);
+        let value = number
+            .parse()
+            .map_err(|_| ParseError::new(self.source, number, "number"))?;
+        self.rest = rest;
+        Ok(value)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        match self.rest.strip_prefix(expected) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn skip_whitespaces(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    /// Error pointing at the next character, or at the end of input if there is none
+    fn unexpected(&self, expected: &str) -> ParseError {
+        match self.rest.chars().next() {
+            Some(chr) => ParseError::new(self.source, &self.rest[..chr.len_utf8()], expected),
+            None => ParseError::missing(self.source, expected),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tokenize, Token};
+
+    #[test]
+    fn tokenize_string() {
+        assert_eq!(tokenize("\"hi\""), Ok(Token::String("hi".to_string())));
+    }
+
+    #[test]
+    fn tokenize_number() {
+        assert_eq!(tokenize("34"), Ok(Token::Number(34)));
+    }
+
+    #[test]
+    fn tokenize_negative_number() {
+        assert_eq!(tokenize("-234"), Ok(Token::Number(-234)));
+    }
+
+    #[test]
+    fn tokenize_emtpy_array() {
+        assert_eq!(tokenize("[]"), Ok(Token::Array(Vec::new())));
+    }
+
+    #[test]
+    fn tokenize_single_number_array() {
+        assert_eq!(tokenize("[5]"), Ok(Token::Array(vec![Token::Number(5)])));
+    }
+
+    #[test]
+    fn tokenize_multiple_number_array() {
+        assert_eq!(
+            tokenize("[13,-8]"),
+            Ok(Token::Array(vec![Token::Number(13), Token::Number(-8)]))
+        );
+    }
+
+    #[test]
+    fn tokenize_single_string_array() {
+        assert_eq!(
+            tokenize("[\"lonely\"]"),
+            Ok(Token::Array(vec![Token::String("lonely".to_string())]))
+        );
+    }
+
+    #[test]
+    fn tokenize_multiple_string_array() {
+        assert_eq!(
+            tokenize("[\"better\", \"together\"]"),
+            Ok(Token::Array(vec![
+                Token::String("better".to_string()),
+                Token::String("together".to_string())
+            ]))
+        );
+    }
+
+    #[test]
+    fn tokenize_nested_array() {
+        assert_eq!(
+            tokenize("[[]]"),
+            Ok(Token::Array(vec![Token::Array(Vec::new())]))
+        );
+
+        assert_eq!(
+            tokenize("[5, [\"nested\", -10]]"),
+            Ok(Token::Array(vec![
+                Token::Number(5),
+                Token::Array(vec![
+                    Token::String("nested".to_string()),
+                    Token::Number(-10)
+                ])
+            ]))
+        );
+    }
+
+    #[test]
+    fn tokenize_with_whitespace() {
+        assert_eq!(
+            tokenize(" [3,  5]"),
+            Ok(Token::Array(vec![Token::Number(3), Token::Number(5)]))
+        );
+
+        assert_eq!(
+            tokenize("\"string with whitespaces\""),
+            Ok(Token::String("string with whitespaces".to_string()))
+        );
+    }
+
+    #[test]
+    fn tokenize_empty_object() {
+        assert_eq!(tokenize("{}"), Ok(Token::Object(Vec::new())));
+    }
+
+    #[test]
+    fn tokenize_object() {
+        assert_eq!(
+            tokenize("{\"a\": 12}"),
+            Ok(Token::Object(vec![("a".to_string(), Token::Number(12))]))
+        );
+
+        assert_eq!(
+            tokenize("{\"a\": -9, \"boo\": \"false\"}"),
+            Ok(Token::Object(vec![
+                ("a".to_string(), Token::Number(-9)),
+                ("boo".to_string(), Token::String("false".to_string()))
+            ]))
+        );
+    }
+
+    #[test]
+    fn tokenize_nested_object() {
+        assert_eq!(
+            tokenize("{\"foo\": -3, \"bar\": {\"cat\": \"red\", \"dog\": 8}}"),
+            Ok(Token::Object(vec![
+                ("foo".to_string(), Token::Number(-3)),
+                (
+                    "bar".to_string(),
+                    Token::Object(vec![
+                        ("cat".to_string(), Token::String("red".to_string())),
+                        ("dog".to_string(), Token::Number(8))
+                    ])
+                )
+            ]))
+        );
+    }
+
+    #[test]
+    fn tokenize_object_in_array() {
+        assert_eq!(
+            tokenize("[\"a\", -8, {\"foo\": 6}, \"b\"]"),
+            Ok(Token::Array(vec![
+                Token::String("a".to_string()),
+                Token::Number(-8),
+                Token::Object(vec![("foo".to_string(), Token::Number(6))]),
+                Token::String("b".to_string())
+            ]))
+        );
+    }
+
+    #[test]
+    fn tokenize_array_in_object() {
+        assert_eq!(
+            tokenize("{\"a\": [\"boo\", -8], \"foo\": 2}"),
+            Ok(Token::Object(vec![
+                (
+                    "a".to_string(),
+                    Token::Array(vec![Token::String("boo".to_string()), Token::Number(-8)])
+                ),
+                ("foo".to_string(), Token::Number(2))
+            ]))
+        )
+    }
+
+    #[test]
+    fn tokenize_errors() {
+        let err = tokenize("[1, 2").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (6, "',' or ']'"));
+
+        let err = tokenize("{\"a\": 1,\n 2}").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "2"));
+
+        let err = tokenize("abc").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (1, "a"));
+
+        let err = tokenize("-").unwrap_err();
+        assert_eq!(err.expected, "number");
+
+        assert!(tokenize("[1] 2").is_err());
+    }
+}
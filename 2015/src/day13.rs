@@ -0,0 +1,140 @@
+use aoc_core::{
+    parse::{integer, parse_lines, parse_with, word},
+    Answer, ParseError, Solution,
+};
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::char,
+    combinator::value,
+    sequence::{terminated, tuple},
+};
+
+/// Happiness of the first person when sitting next to the second
+type Scores<'a> = HashMap<(&'a str, &'a str), i32>;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Scores<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_scores(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(scores: &Scores) -> i32 {
+    let names = get_names(scores);
+
+    names
+        .iter()
+        .permutations(names.len())
+        .map(|mut circle| {
+            circle.push(circle[0]);
+            circle
+                .windows(2)
+                .map(|names| scores[&(*names[0], *names[1])] + scores[&(*names[1], *names[0])])
+                .sum::<i32>()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+fn part_two(scores: &Scores) -> i32 {
+    let names = get_names(scores);
+
+    names
+        .iter()
+        .permutations(names.len())
+        .map(|mut circle| {
+            let mut worst_pair = i32::MAX;
+            circle.push(circle[0]);
+            circle
+                .windows(2)
+                .map(|names| {
+                    let score = scores[&(*names[0], *names[1])] + scores[&(*names[1], *names[0])];
+                    worst_pair = worst_pair.min(score);
+                    score
+                })
+                .sum::<i32>()
+                - worst_pair
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+fn get_names<'a>(scores: &Scores<'a>) -> Vec<&'a str> {
+    scores
+        .keys()
+        .flat_map(|&(name1, name2)| [name1, name2])
+        .unique()
+        .collect::<Vec<_>>()
+}
+
+fn parse_scores(data: &str) -> Result<Scores<'_>, ParseError> {
+    let scores = parse_lines(data, |line| {
+        let (person1, _, sign, _, happiness, _, person2) = parse_with(
+            line,
+            tuple((
+                word,
+                tag(" would "),
+                alt((value(1, tag("gain")), value(-1, tag("lose")))),
+                char(' '),
+                integer::<i32>,
+                tag(" happiness units by sitting next to "),
+                terminated(word, char('.')),
+            )),
+            "line such as \"Alice would gain 54 happiness units by sitting next to Bob.\"",
+        )?;
+        Ok(((person1, person2), sign * happiness))
+    })?
+    .into_iter()
+    .collect::<Scores>();
+    if scores.is_empty() {
+        return Err(ParseError::missing(data, "happiness scores"));
+    }
+
+    // Every person needs a score for every other person to seat them in any order
+    let names = get_names(&scores);
+    for (&name1, &name2) in names.iter().tuple_combinations() {
+        for pair in [(name1, name2), (name2, name1)] {
+            if !scores.contains_key(&pair) {
+                return Err(ParseError::missing(
+                    data,
+                    format!("happiness of {} next to {}", pair.0, pair.1),
+                ));
+            }
+        }
+    }
+
+    Ok(scores)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_scores("Alice would gain 54 happiness units by sitting next to Bob.\nBob would win 3 happiness units by sitting next to Alice.").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 11, "win"));
+
+        let err = parse_scores("Alice would gain 54 happiness units by sitting next to Bob.")
+            .unwrap_err();
+        assert_eq!(err.expected, "happiness of Bob next to Alice");
+
+        let err = parse_scores("").unwrap_err();
+        assert_eq!(err.expected, "happiness scores");
+    }
+}
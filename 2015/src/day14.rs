@@ -0,0 +1,99 @@
+use aoc_core::{
+    parse::{numbers, parse_lines},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Vec<(usize, usize, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_reindeer(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input, 2503).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input, 2503).into()
+    }
+}
+
+fn part_one(data: &[(usize, usize, usize)], total_time: usize) -> usize {
+    data.iter()
+        .map(|&(speed, fly_time, rest_time)| distance_after(total_time, speed, fly_time, rest_time))
+        .max()
+        .expect("Could not find winning distance")
+}
+
+fn part_two(data: &[(usize, usize, usize)], total_time: usize) -> usize {
+    let mut score = vec![0; data.len()];
+
+    (1..=total_time).for_each(|time| {
+        let distances = distances_after(time, data);
+        let max_distance = distances
+            .iter()
+            .max()
+            .unwrap_or_else(|| panic!("Could not find maximum distance at time {time}"));
+
+        distances.iter().enumerate().for_each(|(index, distance)| {
+            if distance == max_distance {
+                score[index] += 1;
+            }
+        })
+    });
+
+    score
+        .into_iter()
+        .max()
+        .expect("Could not find maximum score")
+}
+
+fn parse_reindeer(data: &str) -> Result<Vec<(usize, usize, usize)>, ParseError> {
+    let reindeer = parse_lines(data, |line| match numbers(line)?[..] {
+        [speed, fly_time, rest_time] if fly_time + rest_time > 0 => {
+            Ok((speed, fly_time, rest_time))
+        }
+        _ => Err(ParseError::new(
+            line,
+            line,
+            "speed, flying time and resting time of a reindeer",
+        )),
+    })?;
+    if reindeer.is_empty() {
+        return Err(ParseError::missing(data, "reindeer"));
+    }
+
+    Ok(reindeer)
+}
+
+fn distance_after(total_time: usize, speed: usize, fly_time: usize, rest_time: usize) -> usize {
+    let loop_time = fly_time + rest_time;
+    let completed_loops = total_time / loop_time;
+    let remaining_time = (total_time % loop_time).min(fly_time);
+
+    (completed_loops * fly_time + remaining_time) * speed
+}
+
+fn distances_after(total_time: usize, data: &[(usize, usize, usize)]) -> Vec<usize> {
+    data.iter()
+        .map(|&(speed, fly_time, rest_time)| distance_after(total_time, speed, fly_time, rest_time))
+        .collect::<Vec<_>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_reindeer("").unwrap_err();
+        assert_eq!(err.expected, "reindeer");
+
+        let data = "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.\nDancer can fly 16 km/s for 0 seconds, but then must rest for 0 seconds.";
+        let err = parse_reindeer(data).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -0,0 +1,155 @@
+use aoc_core::{
+    parse::{comma_list, integer, key_value, parse_lines, parse_with, word},
+    Answer, ParseError, Solution,
+};
+
+use nom::{character::complete::space1, sequence::separated_pair};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<Vec<isize>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_ingredients(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(ingredients: &[Vec<isize>]) -> isize {
+    let total_teaspoons = 100;
+    let total_ingredients = ingredients.len();
+    let total_properties = ingredients[0].len();
+
+    let combinations = get_combinations_sum(total_teaspoons, ingredients.len());
+
+    combinations
+        .iter()
+        .map(|combination| {
+            (0..(total_properties - 1))
+                .map(|property_index| {
+                    isize::max(
+                        (0..total_ingredients)
+                            .map(|ingredient_index| {
+                                ingredients[ingredient_index][property_index]
+                                    * combination[ingredient_index] as isize
+                            })
+                            .sum(),
+                        0,
+                    )
+                })
+                .product::<isize>()
+        })
+        .max()
+        .expect("Could not find maximum score")
+}
+
+fn part_two(ingredients: &[Vec<isize>]) -> isize {
+    let total_teaspoons = 100;
+    let total_ingredients = ingredients.len();
+    let total_properties = ingredients[0].len();
+
+    let combinations = get_combinations_sum(total_teaspoons, ingredients.len());
+
+    combinations
+        .into_iter()
+        .map(|combination| {
+            (0..(total_properties - 1))
+                .filter_map(|property_index| {
+                    let calories = (0..total_ingredients)
+                        .map(|ingredient_index| {
+                            ingredients[ingredient_index][total_properties - 1]
+                                * combination[ingredient_index] as isize
+                        })
+                        .sum::<isize>();
+                    (calories == 500).then(|| {
+                        isize::max(
+                            (0..total_ingredients)
+                                .map(|ingredient_index| {
+                                    ingredients[ingredient_index][property_index]
+                                        * combination[ingredient_index] as isize
+                                })
+                                .sum(),
+                            0,
+                        )
+                    })
+                })
+                .product::<isize>()
+        })
+        .max()
+        .expect("Could not find maximum score")
+}
+
+fn parse_ingredients(data: &str) -> Result<Vec<Vec<isize>>, ParseError> {
+    let ingredients = parse_lines(data, |line| {
+        let property = separated_pair(word, space1, integer);
+        let (_name, properties) = parse_with(
+            line,
+            key_value(word, comma_list(property)),
+            "ingredient followed by properties such as \"capacity -1\"",
+        )?;
+        Ok(properties
+            .into_iter()
+            .map(|(_property, value)| value)
+            .collect::<Vec<_>>())
+    })?;
+
+    let Some(first) = ingredients.first() else {
+        return Err(ParseError::missing(data, "ingredients"));
+    };
+    // Every ingredient lists the same properties, with the calories last
+    if let Some(index) = ingredients
+        .iter()
+        .position(|properties| properties.len() != first.len())
+    {
+        let line = data.lines().nth(index).unwrap_or(data);
+        return Err(ParseError::new(
+            data,
+            line,
+            format!("{} properties", first.len()),
+        ));
+    }
+
+    Ok(ingredients)
+}
+
+fn get_combinations_sum(total: usize, depth: usize) -> Vec<Vec<usize>> {
+    if depth == 1 {
+        return vec![vec![total]];
+    }
+
+    (0..=total)
+        .flat_map(|x| {
+            let mut combinations = get_combinations_sum(total - x, depth - 1);
+            combinations
+                .iter_mut()
+                .for_each(|combination| combination.push(x));
+            combinations
+        })
+        .collect::<Vec<Vec<_>>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_ingredients("").unwrap_err();
+        assert_eq!(err.expected, "ingredients");
+
+        let data = "Butterscotch: capacity -1, calories 8\nCinnamon: capacity 2";
+        let err = parse_ingredients(data).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 1, "2 properties")
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use aoc_core::{
+    parse::{comma_list, integer, key_value, parse_lines, parse_with, word},
+    Answer, ParseError, Solution,
+};
+use nom::{
+    bytes::complete::tag,
+    sequence::{preceded, separated_pair},
+};
+use std::collections::HashMap;
+
+type Aunt<'a> = (usize, Vec<(&'a str, usize)>);
+
+enum PropertyType {
+    Smaller,
+    Equal,
+    Greater,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Vec<Aunt<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_aunts(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(aunts: &[Aunt]) -> Option<usize> {
+    let known_properties = get_known_properties();
+
+    aunts.iter().find_map(|(number, properties)| {
+        if properties
+            .iter()
+            .any(|(property, amount)| *amount != known_properties[property].1)
+        {
+            return None;
+        }
+        Some(*number)
+    })
+}
+
+fn part_two(aunts: &[Aunt]) -> Option<usize> {
+    let known_properties = get_known_properties();
+
+    aunts.iter().find_map(|(number, properties)| {
+        if !properties.iter().all(|(property, amount)| {
+            let (property_type, known_amount) = &known_properties[property];
+
+            match property_type {
+                PropertyType::Smaller => amount < known_amount,
+                PropertyType::Equal => amount == known_amount,
+                PropertyType::Greater => amount > known_amount,
+            }
+        }) {
+            return None;
+        }
+        Some(*number)
+    })
+}
+
+fn parse_aunts(data: &str) -> Result<Vec<Aunt<'_>>, ParseError> {
+    let known_properties = get_known_properties();
+
+    parse_lines(data, |line| {
+        let aunt = parse_with(
+            line,
+            separated_pair(
+                preceded(tag("Sue "), integer),
+                tag(": "),
+                comma_list(key_value(word, integer)),
+            ),
+            "aunt",
+        )?;
+
+        match aunt
+            .1
+            .iter()
+            .find(|(property, _)| !known_properties.contains_key(property))
+        {
+            Some((property, _)) => Err(ParseError::new(line, property, "known property")),
+            None => Ok(aunt),
+        }
+    })
+}
+
+fn get_known_properties() -> HashMap<&'static str, (PropertyType, usize)> {
+    let mut known_properties: HashMap<&'static str, (PropertyType, usize)> = HashMap::new();
+    known_properties.insert("children", (PropertyType::Equal, 3));
+    known_properties.insert("cats", (PropertyType::Greater, 7));
+    known_properties.insert("samoyeds", (PropertyType::Equal, 2));
+    known_properties.insert("pomeranians", (PropertyType::Smaller, 3));
+    known_properties.insert("akitas", (PropertyType::Equal, 0));
+    known_properties.insert("vizslas", (PropertyType::Equal, 0));
+    known_properties.insert("goldfish", (PropertyType::Smaller, 5));
+    known_properties.insert("trees", (PropertyType::Greater, 3));
+    known_properties.insert("cars", (PropertyType::Equal, 2));
+    known_properties.insert("perfumes", (PropertyType::Equal, 1));
+
+    known_properties
+}
@@ -0,0 +1,60 @@
+use aoc_core::{
+    parse::{integer, parse_lines, parse_with},
+    Answer, ParseError, Solution,
+};
+
+use itertools::Itertools;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_containers(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(containers: &[usize]) -> usize {
+    let eggnog = 150;
+
+    (1..=containers.len())
+        .flat_map(|num_containers| {
+            containers
+                .iter()
+                .combinations(num_containers)
+                .map(|combination| combination.into_iter().sum::<usize>())
+        })
+        .filter(|&volume| volume == eggnog)
+        .count()
+}
+
+fn part_two(containers: &[usize]) -> usize {
+    let eggnog = 150;
+
+    let mut valid_combinations = Vec::new();
+    for num_containers in 1..=containers.len() {
+        valid_combinations = containers
+            .iter()
+            .combinations(num_containers)
+            .filter(|combination| combination.iter().copied().sum::<usize>() == eggnog)
+            .collect::<Vec<_>>();
+        if !valid_combinations.is_empty() {
+            break;
+        }
+    }
+
+    valid_combinations.len()
+}
+
+fn parse_containers(data: &str) -> Result<Vec<usize>, ParseError> {
+    parse_lines(data, |line| parse_with(line, integer, "container volume"))
+}
@@ -0,0 +1,106 @@
+use aoc_core::{
+    cycle::simulate_with_cycle_skip,
+    grid::{Edges, Grid},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_lights(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(lights: &Grid<bool>) -> usize {
+    simulate_with_cycle_skip(
+        lights.clone(),
+        |lights| (lights.clone(), count_lit(lights)),
+        |lights| *lights = step(lights),
+        100,
+    )
+    .value
+}
+
+fn part_two(lights: &Grid<bool>) -> usize {
+    let mut lights = lights.clone();
+    light_corners(&mut lights);
+
+    simulate_with_cycle_skip(
+        lights,
+        |lights| (lights.clone(), count_lit(lights)),
+        |lights| {
+            *lights = step(lights);
+            light_corners(lights);
+        },
+        100,
+    )
+    .value
+}
+
+fn count_lit(lights: &Grid<bool>) -> usize {
+    lights.values().filter(|&state| *state).count()
+}
+
+fn step(lights: &Grid<bool>) -> Grid<bool> {
+    let states = lights
+        .iter()
+        .map(|(coord, state)| {
+            let lit_neighbors = lights
+                .all_neighbours(coord, Edges::Bounded)
+                .filter(|&neighbor| lights[neighbor])
+                .count();
+
+            if *state {
+                lit_neighbors == 2 || lit_neighbors == 3
+            } else {
+                lit_neighbors == 3
+            }
+        })
+        .collect();
+
+    Grid::from_cells(lights.width(), states)
+}
+
+fn light_corners(lights: &mut Grid<bool>) {
+    let (right, bottom) = (lights.width() - 1, lights.height() - 1);
+    for corner in [(0, 0), (right, 0), (0, bottom), (right, bottom)] {
+        lights[corner] = true;
+    }
+}
+
+fn parse_lights(data: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(data, "# or .", |character| match character {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blinker() {
+        // Repeats every two steps, so the steps after the first cycle are skipped
+        let lights = parse_lights(".....\n..#..\n..#..\n..#..\n.....").unwrap();
+        assert_eq!(part_one(&lights), 3);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_lights(".#.\n.o.\n...").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "o"));
+    }
+}
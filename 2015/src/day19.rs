@@ -0,0 +1,129 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{
+    parse::{all_chars, blocks, parse_lines, parse_with, word},
+    Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+use nom::{bytes::complete::tag, sequence::separated_pair};
+
+type Machine<'a> = (Vec<(&'a str, &'a str)>, &'a str);
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = Machine<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_machine(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one((rules, start_molecule): &Machine) -> usize {
+    let mut replacement_map: HashMap<String, Vec<String>> = HashMap::new();
+    for &(start, end) in rules {
+        replacement_map
+            .entry(start.to_owned())
+            .or_default()
+            .push(end.to_owned());
+    }
+
+    let replacements = replacement_map.into_iter().collect::<Vec<_>>();
+
+    let replaced_strings = get_replacements(start_molecule, &replacements);
+    replaced_strings.iter().unique().count()
+}
+
+fn part_two((rules, start_molecule): &Machine) -> Option<usize> {
+    let mut replacement_map: HashMap<String, Vec<String>> = HashMap::new();
+    for &(start, end) in rules {
+        replacement_map
+            .entry(end.to_owned())
+            .or_default()
+            .push(start.to_owned());
+    }
+
+    let replacements = replacement_map
+        .into_iter()
+        .sorted_by_key(|(left, _)| left.len())
+        .rev()
+        .collect::<Vec<_>>();
+
+    let mut queue: Vec<(usize, String)> = Vec::new();
+    queue.push((0, start_molecule.to_string()));
+
+    let mut visited: HashSet<String> = HashSet::new();
+    let medicine = String::from("e");
+
+    'outer: loop {
+        let new_queue_items = if let Some((depth, molecule)) = queue.pop() {
+            get_replacements(&molecule, &replacements)
+                .into_iter()
+                .map(move |new_molecule| (depth + 1, new_molecule))
+        } else {
+            break None;
+        };
+
+        for (depth, molecule) in new_queue_items {
+            if molecule == medicine {
+                break 'outer Some(depth);
+            }
+
+            if !visited.contains(&molecule) {
+                visited.insert(molecule.clone());
+                queue.push((depth, molecule));
+            }
+        }
+    }
+}
+
+fn get_replacements(start_molecule: &str, replacements: &[(String, Vec<String>)]) -> Vec<String> {
+    replacements
+        .iter()
+        .flat_map(|(start, end_vec)| {
+            let indices = start_molecule.match_indices(start);
+            indices.cartesian_product(end_vec)
+        })
+        .map(|((index, start), end)| {
+            let mut new_molecule = start_molecule.to_owned();
+            new_molecule.replace_range(index..index + start.chars().count(), end);
+            new_molecule
+        })
+        .collect::<Vec<_>>()
+}
+
+/// The replacement rules, followed by the starting molecule after a blank line
+fn parse_machine(data: &str) -> Result<Machine<'_>, ParseError> {
+    let mut blocks = blocks(data);
+
+    let rules = blocks
+        .next()
+        .ok_or_else(|| ParseError::missing(data, "replacement"))?;
+    let rules = parse_lines(rules, |line| {
+        parse_with(line, separated_pair(word, tag(" => "), word), "replacement")
+    })
+    .map_err(|err| err.relative_to(data, rules))?;
+
+    let molecule = blocks
+        .next()
+        .ok_or_else(|| ParseError::missing(data, "molecule"))?;
+    let molecule = all_chars(molecule, |chr| chr.is_ascii_alphabetic(), "molecule")
+        .map_err(|err| err.relative_to(data, molecule))?;
+
+    match blocks.next() {
+        Some(extra) => Err(ParseError::new(
+            data,
+            extra.lines().next().unwrap_or(extra),
+            "end of input",
+        )),
+        None => Ok((rules, molecule)),
+    }
+}
@@ -0,0 +1,61 @@
+use aoc_core::{
+    math::divisor_sum,
+    parse::{integer, parse_with, single_line},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_presents(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(*input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(*input).into()
+    }
+}
+
+fn part_one(presents: u64) -> u64 {
+    // Every elf whose number divides the house number delivers ten times its own number
+    (1..)
+        .find(|&house| divisor_sum(house) * 10 >= presents)
+        .expect("Could not find house with enough presents")
+}
+
+fn part_two(presents: u64) -> u64 {
+    // Elves stop after 50 houses, so a house is only visited by the elves that are its number
+    // divided by at most 50
+    (1..)
+        .find(|&house: &u64| {
+            let delivered = (1..=50)
+                .filter(|&visit| house.is_multiple_of(visit))
+                .map(|visit| house / visit)
+                .sum::<u64>();
+            delivered * 11 >= presents
+        })
+        .expect("Could not find house with enough presents")
+}
+
+fn parse_presents(data: &str) -> Result<u64, ParseError> {
+    let line = single_line(data, "number of presents")?;
+    parse_with(line, integer, "number of presents").map_err(|err| err.relative_to(data, line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_presents() {
+        assert_eq!(part_one(70), 4);
+        assert_eq!(part_one(71), 6);
+        assert_eq!(part_two(77), 4);
+    }
+}
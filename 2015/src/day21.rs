@@ -0,0 +1,109 @@
+use aoc_core::{
+    parse::{integer, parse_with},
+    Answer, ParseError, Solution,
+};
+use itertools::{chain, iproduct, Itertools};
+use nom::{
+    bytes::complete::tag,
+    sequence::{pair, preceded},
+};
+use std::iter::once;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = (usize, usize, usize);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_boss(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(boss: &(usize, usize, usize)) -> Option<usize> {
+    let health: usize = 100;
+
+    let winning_outfit = get_outfits().into_iter().find(|(_, damage, armor)| {
+        let boss_damage = usize::max(boss.1.saturating_sub(*armor), 1);
+        let player_damage = usize::max(damage.saturating_sub(boss.2), 1);
+        let boss_turns = health.div_ceil(boss_damage);
+        let player_turns = boss.0.div_ceil(player_damage);
+        player_turns <= boss_turns
+    });
+
+    winning_outfit.map(|(cost, _, _)| cost)
+}
+
+fn part_two(boss: &(usize, usize, usize)) -> Option<usize> {
+    let health: usize = 100;
+
+    let losing_outfit = get_outfits().into_iter().rev().find(|(_, damage, armor)| {
+        let boss_damage = if boss.1 > *armor { boss.1 - armor } else { 1 };
+        let player_damage = if *damage > boss.2 { damage - boss.2 } else { 1 };
+        let boss_turns = health.div_ceil(boss_damage);
+        let player_turns = boss.0.div_ceil(player_damage);
+        player_turns > boss_turns
+    });
+
+    losing_outfit.map(|(cost, _, _)| cost)
+}
+
+fn get_outfits() -> Vec<(usize, usize, usize)> {
+    let weapons = [(8, 4, 0), (10, 5, 0), (25, 6, 0), (40, 7, 0), (74, 8, 0)];
+    let armor = [(13, 0, 1), (31, 0, 2), (53, 0, 3), (75, 0, 4), (102, 0, 5)];
+    let rings = [
+        (25, 1, 0),
+        (50, 2, 0),
+        (100, 3, 0),
+        (20, 0, 1),
+        (40, 0, 2),
+        (80, 0, 3),
+    ];
+
+    let weapon_combinations = weapons.iter().combinations(1);
+    let armor_combinations = chain!(armor.iter().combinations(1), once(vec![&(0, 0, 0)]));
+    let ring_combinations = chain!(
+        rings.iter().combinations(2),
+        rings.iter().combinations(1),
+        once(vec![&(0, 0, 0)])
+    );
+
+    let mut outfits = iproduct!(weapon_combinations, armor_combinations, ring_combinations)
+        .map(|(weapon_vec, armor_vec, ring_vec)| {
+            chain!(weapon_vec.iter(), armor_vec.iter(), ring_vec.iter())
+                .fold((0, 0, 0), |acc, cur| {
+                    (acc.0 + cur.0, acc.1 + cur.1, acc.2 + cur.2)
+                })
+        })
+        .collect::<Vec<_>>();
+
+    outfits.sort_by_key(|(cost, _, _)| *cost);
+
+    outfits
+}
+
+fn parse_boss(data: &str) -> Result<(usize, usize, usize), ParseError> {
+    let [health, damage, armor] = parse_stats(data, ["Hit Points", "Damage", "Armor"])?;
+    Ok((health, damage, armor))
+}
+
+/// The boss's stats, one `Name: value` line each in the order of `names`
+fn parse_stats<const N: usize>(data: &str, names: [&str; N]) -> Result<[usize; N], ParseError> {
+    let mut lines = data.lines();
+    let mut stats = [0; N];
+    for (stat, name) in stats.iter_mut().zip(names) {
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::missing(data, name))?;
+        *stat = parse_with(line, preceded(pair(tag(name), tag(": ")), integer), name)
+            .map_err(|err| err.relative_to(data, line))?;
+    }
+    Ok(stats)
+}
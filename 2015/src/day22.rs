@@ -0,0 +1,285 @@
+use aoc_core::{
+    parse::{integer, parse_with},
+    search::dijkstra,
+    Answer, ParseError, Solution,
+};
+use nom::{
+    bytes::complete::tag,
+    sequence::{pair, preceded},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum PlayerType {
+    Player,
+    Boss,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct State {
+    player_health: usize,
+    boss_health: usize,
+    mana: usize,
+    effects: Vec<Effect>,
+    player_at_turn: PlayerType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Effect {
+    timer: usize,
+    damage: usize,
+    armor: usize,
+    mana: usize,
+    cost: usize,
+}
+
+#[derive(Clone)]
+struct Spell {
+    damage: usize,
+    health: usize,
+    cost: usize,
+}
+
+#[derive(PartialEq)]
+enum Mode {
+    Normal,
+    Hard,
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = (usize, usize);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_boss(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(*input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(*input).into()
+    }
+}
+
+fn part_one(boss: (usize, usize)) -> Option<usize> {
+    get_winning_cost(
+        get_initial_state(boss),
+        boss,
+        get_spells(),
+        get_effects(),
+        Mode::Normal,
+    )
+}
+
+fn part_two(boss: (usize, usize)) -> Option<usize> {
+    get_winning_cost(
+        get_initial_state(boss),
+        boss,
+        get_spells(),
+        get_effects(),
+        Mode::Hard,
+    )
+}
+
+fn get_initial_state(boss: (usize, usize)) -> State {
+    let health = 50;
+    let mana = 500;
+
+    State {
+        player_health: health,
+        boss_health: boss.0,
+        mana,
+        effects: Vec::new(),
+        player_at_turn: PlayerType::Player,
+    }
+}
+
+fn parse_boss(data: &str) -> Result<(usize, usize), ParseError> {
+    let [health, damage] = parse_stats(data, ["Hit Points", "Damage"])?;
+    Ok((health, damage))
+}
+
+/// The boss's stats, one `Name: value` line each in the order of `names`
+fn parse_stats<const N: usize>(data: &str, names: [&str; N]) -> Result<[usize; N], ParseError> {
+    let mut lines = data.lines();
+    let mut stats = [0; N];
+    for (stat, name) in stats.iter_mut().zip(names) {
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::missing(data, name))?;
+        *stat = parse_with(line, preceded(pair(tag(name), tag(": ")), integer), name)
+            .map_err(|err| err.relative_to(data, line))?;
+    }
+    Ok(stats)
+}
+
+fn get_spells() -> Vec<Spell> {
+    let missile = Spell {
+        damage: 4,
+        health: 0,
+        cost: 53,
+    };
+
+    let drain = Spell {
+        damage: 2,
+        health: 2,
+        cost: 73,
+    };
+
+    vec![missile, drain]
+}
+
+fn get_effects() -> Vec<Effect> {
+    let shield = Effect {
+        timer: 6,
+        damage: 0,
+        armor: 7,
+        mana: 0,
+        cost: 113,
+    };
+
+    let poison = Effect {
+        timer: 6,
+        damage: 3,
+        armor: 0,
+        mana: 0,
+        cost: 173,
+    };
+
+    let recharge = Effect {
+        timer: 5,
+        damage: 0,
+        armor: 0,
+        mana: 101,
+        cost: 229,
+    };
+
+    vec![shield, poison, recharge]
+}
+
+fn get_winning_cost(
+    initial_state: State,
+    boss: (usize, usize),
+    spells: Vec<Spell>,
+    effects: Vec<Effect>,
+    mode: Mode,
+) -> Option<usize> {
+    dijkstra(
+        [initial_state],
+        |state| get_next_states(state, boss, &spells, &effects, &mode),
+        |state| state.boss_health == 0,
+    )
+    .goal_distance()
+}
+
+/// States after the next turn, with the mana spent during that turn
+fn get_next_states(
+    current_state: &State,
+    boss: (usize, usize),
+    spells: &[Spell],
+    effects: &[Effect],
+    mode: &Mode,
+) -> Vec<(State, usize)> {
+    let mut next_states = Vec::new();
+
+    let mut boss_health = current_state.boss_health;
+    let mut mana = current_state.mana;
+    let mut armor = 0;
+
+    let mut health = current_state.player_health;
+
+    if *mode == Mode::Hard && current_state.player_at_turn == PlayerType::Player {
+        if health <= 1 {
+            return next_states;
+        } else {
+            health -= 1;
+        }
+    }
+
+    let mut new_effects = Vec::new();
+
+    for effect in current_state.effects.iter() {
+        let timer = effect.timer - 1;
+        if boss_health <= effect.damage {
+            next_states.push((
+                State {
+                    boss_health: 0,
+                    ..current_state.clone()
+                },
+                0,
+            ));
+            return next_states;
+        }
+        boss_health -= effect.damage;
+        armor += effect.armor;
+        mana += effect.mana;
+
+        if timer > 0 {
+            new_effects.push(Effect {
+                timer,
+                ..effect.clone()
+            })
+        }
+    }
+
+    match current_state.player_at_turn {
+        PlayerType::Player => {
+            for spell in spells.iter() {
+                if spell.cost > mana {
+                    continue;
+                }
+                next_states.push((
+                    State {
+                        player_health: health + spell.health,
+                        boss_health: boss_health.saturating_sub(spell.damage),
+                        mana: mana - spell.cost,
+                        effects: new_effects.clone(),
+                        player_at_turn: PlayerType::Boss,
+                    },
+                    spell.cost,
+                ));
+            }
+            for effect in effects.iter() {
+                // Effects are told apart by their cost, since their timers run out
+                if new_effects.iter().any(|active| active.cost == effect.cost) {
+                    continue;
+                }
+                if effect.cost > mana {
+                    continue;
+                }
+                let mut tmp = new_effects.clone();
+                tmp.push(effect.clone());
+                next_states.push((
+                    State {
+                        player_health: health,
+                        boss_health,
+                        mana: mana - effect.cost,
+                        effects: tmp,
+                        player_at_turn: PlayerType::Boss,
+                    },
+                    effect.cost,
+                ))
+            }
+        }
+        PlayerType::Boss => {
+            let boss_damage = usize::max(boss.1, armor + 1) - armor;
+            if health <= boss_damage {
+                return next_states;
+            }
+            next_states.push((
+                State {
+                    player_health: health - boss_damage,
+                    boss_health,
+                    mana,
+                    effects: new_effects,
+                    player_at_turn: PlayerType::Player,
+                },
+                0,
+            ))
+        }
+    }
+
+    next_states
+}
@@ -0,0 +1,796 @@
+use aoc_core::{parse::parse_lines, Answer, ParseError, Solution};
+use std::{
+    collections::{BTreeSet, VecDeque},
+    fmt::Display,
+    str::FromStr,
+};
+
+/// Register named by a lowercase letter
+pub type Register = char;
+pub type Offset = isize;
+
+/// Number of registers, one for every lowercase letter
+const REGISTERS: usize = 26;
+
+/// Steps after which a program is assumed to never halt
+pub const MAX_STEPS: usize = 10_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Half(Register),
+    Triple(Register),
+    Increment(Register),
+    Jump(Offset),
+    JumpIfEven(Register, Offset),
+    JumpIfOne(Register, Offset),
+}
+
+impl Instruction {
+    fn register(&self) -> Option<Register> {
+        match *self {
+            Instruction::Half(register)
+            | Instruction::Triple(register)
+            | Instruction::Increment(register)
+            | Instruction::JumpIfEven(register, _)
+            | Instruction::JumpIfOne(register, _) => Some(register),
+            Instruction::Jump(_) => None,
+        }
+    }
+
+    fn offset(&self) -> Option<Offset> {
+        match *self {
+            Instruction::Jump(offset)
+            | Instruction::JumpIfEven(_, offset)
+            | Instruction::JumpIfOne(_, offset) => Some(offset),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Half(register) => write!(f, "hlf {register}"),
+            Instruction::Triple(register) => write!(f, "tpl {register}"),
+            Instruction::Increment(register) => write!(f, "inc {register}"),
+            Instruction::Jump(offset) => write!(f, "jmp {offset:+}"),
+            Instruction::JumpIfEven(register, offset) => write!(f, "jie {register}, {offset:+}"),
+            Instruction::JumpIfOne(register, offset) => write!(f, "jio {register}, {offset:+}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuntimeError {
+    /// Instruction at `pc` would take the register past `u64::MAX`
+    Overflow {
+        pc: usize,
+        register: Register,
+    },
+    /// Jump at `pc` would land before the first instruction
+    JumpBeforeStart {
+        pc: usize,
+        offset: Offset,
+    },
+    /// Instruction `pc` came up again with the same registers, so the program never halts
+    InfiniteLoop {
+        pc: usize,
+        steps: usize,
+    },
+    StepLimit(usize),
+}
+
+impl Display for RuntimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuntimeError::Overflow { pc, register } => {
+                write!(f, "Register {register} overflows at instruction {pc}")
+            }
+            RuntimeError::JumpBeforeStart { pc, offset } => write!(
+                f,
+                "Jump by {offset:+} at instruction {pc} lands before the first instruction"
+            ),
+            RuntimeError::InfiniteLoop { pc, steps } => write!(
+                f,
+                "Program loops forever: instruction {pc} came up again with the same registers after {steps} steps"
+            ),
+            RuntimeError::StepLimit(steps) => write!(f, "Program did not halt within {steps} steps"),
+        }
+    }
+}
+
+impl std::error::Error for RuntimeError {}
+
+/// State of the register machine: the registers and the instruction that runs next
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Runtime {
+    registers: [u64; REGISTERS],
+    pc: usize,
+    steps: usize,
+}
+
+impl Runtime {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_register(&mut self, register: Register, value: u64) {
+        self.registers[register_index(register)] = value;
+    }
+
+    pub fn get_register(&self, register: Register) -> u64 {
+        self.registers[register_index(register)]
+    }
+
+    /// Index of the instruction that runs next
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// Number of instructions run so far
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn is_halted(&self, instructions: &[Instruction]) -> bool {
+        self.pc >= instructions.len()
+    }
+
+    /// Runs the next instruction, leaving the runtime untouched if it fails or the program has halted
+    pub fn step(&mut self, instructions: &[Instruction]) -> Result<(), RuntimeError> {
+        let pc = self.pc;
+        let Some(&instruction) = instructions.get(pc) else {
+            return Ok(());
+        };
+
+        let overflow = |register| RuntimeError::Overflow { pc, register };
+        let offset = match instruction {
+            Instruction::Half(register) => {
+                self.set_register(register, self.get_register(register) / 2);
+                1
+            }
+            Instruction::Triple(register) => {
+                let value = self.get_register(register).checked_mul(3);
+                self.set_register(register, value.ok_or_else(|| overflow(register))?);
+                1
+            }
+            Instruction::Increment(register) => {
+                let value = self.get_register(register).checked_add(1);
+                self.set_register(register, value.ok_or_else(|| overflow(register))?);
+                1
+            }
+            Instruction::Jump(offset) => offset,
+            Instruction::JumpIfEven(register, offset) => {
+                if self.get_register(register).is_multiple_of(2) {
+                    offset
+                } else {
+                    1
+                }
+            }
+            Instruction::JumpIfOne(register, offset) => {
+                if self.get_register(register) == 1 {
+                    offset
+                } else {
+                    1
+                }
+            }
+        };
+
+        self.pc = match pc.checked_add_signed(offset) {
+            Some(target) => target,
+            None if offset < 0 => return Err(RuntimeError::JumpBeforeStart { pc, offset }),
+            // Far past the last instruction, which halts the program all the same
+            None => usize::MAX,
+        };
+        self.steps += 1;
+
+        Ok(())
+    }
+
+    /// Runs the program until it halts, giving up once `max_steps` instructions have run in total
+    /// or the program is caught in a loop
+    pub fn run(
+        &mut self,
+        instructions: &[Instruction],
+        max_steps: usize,
+    ) -> Result<(), RuntimeError> {
+        self.run_with(instructions, max_steps, |_runtime, _instruction| {})
+    }
+
+    /// Same as [`Runtime::run`], calling `on_step` before every instruction
+    pub fn run_with(
+        &mut self,
+        instructions: &[Instruction],
+        max_steps: usize,
+        mut on_step: impl FnMut(&Runtime, Instruction),
+    ) -> Result<(), RuntimeError> {
+        // A program that never halts has to jump back at some point, so it is enough to look at
+        // the state after every backward jump. Brent's algorithm finds a repeat among those states
+        // while keeping only one of them, which is replaced at every power of two.
+        let mut saved = None;
+        let (mut power, mut length) = (1, 0);
+
+        while let Some(&instruction) = instructions.get(self.pc) {
+            if self.steps >= max_steps {
+                return Err(RuntimeError::StepLimit(max_steps));
+            }
+
+            on_step(self, instruction);
+            let pc = self.pc;
+            self.step(instructions)?;
+
+            if self.pc > pc {
+                continue;
+            }
+            if saved == Some((self.pc, self.registers)) {
+                return Err(RuntimeError::InfiniteLoop {
+                    pc: self.pc,
+                    steps: self.steps,
+                });
+            }
+            length += 1;
+            if length == power {
+                saved = Some((self.pc, self.registers));
+                power *= 2;
+                length = 0;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Line of the execution trace for the instruction that runs next, with the given registers
+    pub fn trace_line(&self, instructions: &[Instruction], registers: &[Register]) -> String {
+        let instruction = instructions
+            .get(self.pc)
+            .map_or_else(|| String::from("halt"), ToString::to_string);
+        let mut line = format!("{:>8}  {:>4}  {instruction:<10}", self.steps, self.pc);
+        for &register in registers {
+            line.push_str(&format!("  {register}={}", self.get_register(register)));
+        }
+        line
+    }
+}
+
+fn register_index(register: Register) -> usize {
+    register as usize - 'a' as usize
+}
+
+/// Registers that the program uses, in alphabetical order
+pub fn get_registers(instructions: &[Instruction]) -> Vec<Register> {
+    let mut registers = instructions
+        .iter()
+        .filter_map(Instruction::register)
+        .collect::<Vec<_>>();
+    registers.sort_unstable();
+    registers.dedup();
+    registers
+}
+
+/// Listing of the program with the index of every instruction and the target of every jump
+pub fn disassemble(instructions: &[Instruction]) -> String {
+    let mut listing = String::new();
+    for (pc, instruction) in instructions.iter().enumerate() {
+        let mut line = format!("{pc:>4}  {instruction}");
+        if let Some(offset) = instruction.offset() {
+            let target = match pc.checked_add_signed(offset) {
+                Some(target) if target < instructions.len() => target.to_string(),
+                None if offset < 0 => String::from("before start"),
+                _ => String::from("halt"),
+            };
+            line = format!("{line:<20}-> {target}");
+        }
+        listing.push_str(&line);
+        listing.push('\n');
+    }
+    listing
+}
+
+/// Number of instructions the debugger remembers to step back over
+const HISTORY_SIZE: usize = 100_000;
+
+pub const DEBUGGER_HELP: &str = "\
+step [n], s      run the next n instructions (default 1)
+reverse [n], r   go back n instructions (default 1)
+continue, c      run until a breakpoint, a watched register changes or the program halts
+break <pc>, b    stop before running instruction pc
+delete <pc>, d   remove the breakpoint at instruction pc
+watch <reg>, w   stop whenever register reg changes
+print, p         show the next instruction and the registers
+help, h          show this help
+quit, q          leave the debugger
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugCommand {
+    Step(usize),
+    Reverse(usize),
+    Continue,
+    Break(usize),
+    Delete(usize),
+    Watch(Register),
+    Print,
+    Help,
+    Quit,
+}
+
+impl FromStr for DebugCommand {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut segments = value.split_whitespace();
+
+        let command = segments
+            .next()
+            .ok_or_else(|| ParseError::missing(value, "command"))?;
+
+        let command = match command {
+            "step" | "s" => DebugCommand::Step(parse_count(value, segments.next())?),
+            "reverse" | "r" => DebugCommand::Reverse(parse_count(value, segments.next())?),
+            "continue" | "c" => DebugCommand::Continue,
+            "break" | "b" => DebugCommand::Break(parse_pc(value, segments.next())?),
+            "delete" | "d" => DebugCommand::Delete(parse_pc(value, segments.next())?),
+            "watch" | "w" => DebugCommand::Watch(parse_register(value, segments.next())?),
+            "print" | "p" => DebugCommand::Print,
+            "help" | "h" => DebugCommand::Help,
+            "quit" | "q" => DebugCommand::Quit,
+            _ => {
+                return Err(ParseError::new(
+                    value,
+                    command,
+                    "step, reverse, continue, break, delete, watch, print, help or quit",
+                ))
+            }
+        };
+
+        match segments.next() {
+            Some(extra) => Err(ParseError::new(value, extra, "end of line")),
+            None => Ok(command),
+        }
+    }
+}
+
+fn parse_count(line: &str, word: Option<&str>) -> Result<usize, ParseError> {
+    match word {
+        Some(word) => word
+            .parse()
+            .map_err(|_| ParseError::new(line, word, "number of instructions")),
+        None => Ok(1),
+    }
+}
+
+fn parse_pc(line: &str, word: Option<&str>) -> Result<usize, ParseError> {
+    let word = word.ok_or_else(|| ParseError::missing(line, "instruction index"))?;
+    word.parse()
+        .map_err(|_| ParseError::new(line, word, "instruction index"))
+}
+
+/// What running one instruction changed, which is enough to undo it
+#[derive(Debug, Clone, Copy)]
+struct Change {
+    pc: usize,
+    /// Register of the instruction with its value before it ran
+    register: Option<(Register, u64)>,
+}
+
+/// Step debugger for a program, which remembers recent changes so it can also step backwards
+pub struct Debugger<'a> {
+    instructions: &'a [Instruction],
+    runtime: Runtime,
+    max_steps: usize,
+    breakpoints: BTreeSet<usize>,
+    watches: Vec<Register>,
+    /// Changes made by the most recent instructions, with the latest at the back
+    history: VecDeque<Change>,
+}
+
+impl<'a> Debugger<'a> {
+    pub fn new(instructions: &'a [Instruction], runtime: Runtime, max_steps: usize) -> Self {
+        Self {
+            instructions,
+            runtime,
+            max_steps,
+            breakpoints: BTreeSet::new(),
+            watches: Vec::new(),
+            history: VecDeque::new(),
+        }
+    }
+
+    pub fn runtime(&self) -> &Runtime {
+        &self.runtime
+    }
+
+    /// Runs a command and returns what it prints
+    pub fn execute(&mut self, command: DebugCommand) -> String {
+        let mut output = String::new();
+
+        match command {
+            DebugCommand::Step(count) => {
+                for _ in 0..count {
+                    if self.advance(&mut output) {
+                        break;
+                    }
+                }
+                output.push_str(&self.print_state());
+            }
+            DebugCommand::Reverse(count) => {
+                for _ in 0..count {
+                    match self.history.pop_back() {
+                        Some(change) => self.undo(change),
+                        None => {
+                            output.push_str("No earlier state in the history\n");
+                            break;
+                        }
+                    }
+                }
+                output.push_str(&self.print_state());
+            }
+            DebugCommand::Continue => {
+                while !self.advance(&mut output) {
+                    let pc = self.runtime.pc();
+                    if self.breakpoints.contains(&pc) {
+                        output.push_str(&format!("Breakpoint at instruction {pc}\n"));
+                        break;
+                    }
+                }
+                output.push_str(&self.print_state());
+            }
+            DebugCommand::Break(pc) => {
+                if pc >= self.instructions.len() {
+                    output.push_str(&format!("There is no instruction {pc}\n"));
+                } else {
+                    self.breakpoints.insert(pc);
+                    output.push_str(&format!("Breakpoint set at instruction {pc}\n"));
+                }
+            }
+            DebugCommand::Delete(pc) => {
+                if self.breakpoints.remove(&pc) {
+                    output.push_str(&format!("Breakpoint at instruction {pc} removed\n"));
+                } else {
+                    output.push_str(&format!("There is no breakpoint at instruction {pc}\n"));
+                }
+            }
+            DebugCommand::Watch(register) => {
+                if !self.watches.contains(&register) {
+                    self.watches.push(register);
+                }
+                output.push_str(&format!("Watching register {register}\n"));
+            }
+            DebugCommand::Print => output.push_str(&self.print_state()),
+            DebugCommand::Help => output.push_str(DEBUGGER_HELP),
+            DebugCommand::Quit => {}
+        }
+
+        output
+    }
+
+    /// Runs one instruction and returns whether execution should stop, which is when the program
+    /// cannot go on or a watched register changed
+    fn advance(&mut self, output: &mut String) -> bool {
+        if self.runtime.is_halted(self.instructions) {
+            output.push_str("Program has halted\n");
+            return true;
+        }
+        if self.runtime.steps() >= self.max_steps {
+            output.push_str(&format!("{}\n", RuntimeError::StepLimit(self.max_steps)));
+            return true;
+        }
+
+        // An instruction changes at most its own register, besides moving the pc
+        let pc = self.runtime.pc();
+        let register = self.instructions[pc]
+            .register()
+            .map(|register| (register, self.runtime.get_register(register)));
+        if let Err(err) = self.runtime.step(self.instructions) {
+            output.push_str(&format!("{err}\n"));
+            return true;
+        }
+
+        let mut stop = false;
+        if let Some((register, old)) = register {
+            let new = self.runtime.get_register(register);
+            if old != new && self.watches.contains(&register) {
+                output.push_str(&format!(
+                    "Register {register} changed from {old} to {new}\n"
+                ));
+                stop = true;
+            }
+        }
+
+        if self.history.len() == HISTORY_SIZE {
+            self.history.pop_front();
+        }
+        self.history.push_back(Change { pc, register });
+
+        stop
+    }
+
+    fn undo(&mut self, change: Change) {
+        self.runtime.pc = change.pc;
+        self.runtime.steps -= 1;
+        if let Some((register, value)) = change.register {
+            self.runtime.set_register(register, value);
+        }
+    }
+
+    /// Next instruction, marked if it has a breakpoint, followed by the register file
+    fn print_state(&self) -> String {
+        let pc = self.runtime.pc();
+        let marker = if self.breakpoints.contains(&pc) {
+            '*'
+        } else {
+            ' '
+        };
+        let instruction = self
+            .instructions
+            .get(pc)
+            .map_or_else(|| String::from("halt"), ToString::to_string);
+
+        let mut registers = get_registers(self.instructions);
+        registers.extend(&self.watches);
+        registers.sort_unstable();
+        registers.dedup();
+
+        let mut state = format!("{marker}{pc:>4}  {instruction}\n      ");
+        for register in registers {
+            state.push_str(&format!(
+                "{register}={}  ",
+                self.runtime.get_register(register)
+            ));
+        }
+        state.push_str(&format!("(step {})\n", self.runtime.steps()));
+        state
+    }
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut segments = value.split_whitespace();
+
+        let instruction = segments
+            .next()
+            .ok_or_else(|| ParseError::missing(value, "instruction"))?;
+
+        let instruction = match instruction {
+            "hlf" => Instruction::Half(parse_register(value, segments.next())?),
+            "tpl" => Instruction::Triple(parse_register(value, segments.next())?),
+            "inc" => Instruction::Increment(parse_register(value, segments.next())?),
+            "jmp" => Instruction::Jump(parse_offset(value, segments.next())?),
+            "jie" | "jio" => {
+                let register = segments
+                    .next()
+                    .map(|register| register.strip_suffix(',').unwrap_or(register));
+                let register = parse_register(value, register)?;
+                let offset = parse_offset(value, segments.next())?;
+                if instruction == "jie" {
+                    Instruction::JumpIfEven(register, offset)
+                } else {
+                    Instruction::JumpIfOne(register, offset)
+                }
+            }
+            _ => {
+                return Err(ParseError::new(
+                    value,
+                    instruction,
+                    "hlf, tpl, inc, jmp, jie or jio",
+                ))
+            }
+        };
+
+        match segments.next() {
+            Some(extra) => Err(ParseError::new(value, extra, "end of line")),
+            None => Ok(instruction),
+        }
+    }
+}
+
+fn parse_register(line: &str, word: Option<&str>) -> Result<Register, ParseError> {
+    let word = word.ok_or_else(|| ParseError::missing(line, "register"))?;
+    let mut chars = word.chars();
+    match (chars.next(), chars.next()) {
+        (Some(register), None) if register.is_ascii_lowercase() => Ok(register),
+        _ => Err(ParseError::new(line, word, "register")),
+    }
+}
+
+fn parse_offset(line: &str, word: Option<&str>) -> Result<Offset, ParseError> {
+    let word = word.ok_or_else(|| ParseError::missing(line, "offset"))?;
+    word.parse::<Offset>()
+        .map_err(|_| ParseError::new(line, word, "offset"))
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(instructions: &[Instruction]) -> Result<u64, RuntimeError> {
+    run_program(instructions, 0)
+}
+
+fn part_two(instructions: &[Instruction]) -> Result<u64, RuntimeError> {
+    run_program(instructions, 1)
+}
+
+/// Register b after running the program with register a set to `a`
+fn run_program(instructions: &[Instruction], a: u64) -> Result<u64, RuntimeError> {
+    let mut runtime = Runtime::new();
+    runtime.set_register('a', a);
+    runtime.run(instructions, MAX_STEPS)?;
+
+    Ok(runtime.get_register('b'))
+}
+
+pub fn parse_instructions(data: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(data, Instruction::try_from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let instructions = parse_instructions("inc a\njio a, +2\ntpl a\ninc a").unwrap();
+        assert!(matches!(instructions[1], Instruction::JumpIfOne('a', 2)));
+        assert_eq!(part_one(&instructions), Ok(0));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_instructions("inc a\njmp +2\njie q1, +4").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 5));
+        assert_eq!(err.expected, "register");
+
+        let err = parse_instructions("dec a").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 1, "dec"));
+
+        let err = parse_instructions("inc a\njio b,").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 7));
+        assert_eq!(err.expected, "offset");
+    }
+
+    #[test]
+    fn test_runtime_errors() {
+        let run = |program: &str| {
+            let instructions = parse_instructions(program).unwrap();
+            Runtime::new().run(&instructions, 1_000)
+        };
+
+        assert_eq!(
+            run("inc a\njmp -2"),
+            Err(RuntimeError::JumpBeforeStart { pc: 1, offset: -2 })
+        );
+        assert_eq!(
+            run("hlf a\njmp -1"),
+            Err(RuntimeError::InfiniteLoop { pc: 0, steps: 4 })
+        );
+        assert_eq!(
+            run("jio a, +3\ninc a\njmp -2\nhlf a\njmp -4"),
+            Err(RuntimeError::InfiniteLoop { pc: 0, steps: 9 })
+        );
+        assert_eq!(run("inc a\njmp -1"), Err(RuntimeError::StepLimit(1_000)));
+        assert_eq!(
+            run("inc a\ntpl a\njmp -1"),
+            Err(RuntimeError::Overflow {
+                pc: 1,
+                register: 'a'
+            })
+        );
+        assert_eq!(run("inc a\njmp +1000"), Ok(()));
+
+        let instructions = parse_instructions("inc b\njie a, +0").unwrap();
+        assert_eq!(
+            part_one(&instructions),
+            Err(RuntimeError::InfiniteLoop { pc: 1, steps: 3 })
+        );
+        assert_eq!(part_two(&instructions), Ok(1));
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let instructions = parse_instructions("inc a\njio a, +2\ntpl a\njmp -4").unwrap();
+        assert_eq!(
+            disassemble(&instructions),
+            "   0  inc a\n   1  jio a, +2     -> 3\n   2  tpl a\n   3  jmp -4        -> before start\n"
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let instructions = parse_instructions("inc b\njio b, +2\ntpl b\nhlf a").unwrap();
+        let registers = get_registers(&instructions);
+        let mut runtime = Runtime::new();
+        let mut trace = Vec::new();
+        runtime
+            .run_with(&instructions, MAX_STEPS, |runtime, _instruction| {
+                trace.push(runtime.trace_line(&instructions, &registers))
+            })
+            .unwrap();
+
+        assert_eq!(registers, vec!['a', 'b']);
+        assert_eq!(trace.len(), 3);
+        assert_eq!(trace[1], "       1     1  jio b, +2   a=0  b=1");
+        assert_eq!(
+            runtime.trace_line(&instructions, &registers),
+            "       3     4  halt        a=0  b=1"
+        );
+    }
+
+    #[test]
+    fn test_parse_debug_commands() {
+        assert_eq!("s".parse(), Ok(DebugCommand::Step(1)));
+        assert_eq!("reverse 12".parse(), Ok(DebugCommand::Reverse(12)));
+        assert_eq!("watch b".parse(), Ok(DebugCommand::Watch('b')));
+
+        let err = "break".parse::<DebugCommand>().unwrap_err();
+        assert_eq!(err.expected, "instruction index");
+        let err = "step 2 3".parse::<DebugCommand>().unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (8, "end of line"));
+        let err = "next".parse::<DebugCommand>().unwrap_err();
+        assert_eq!(err.text, "next");
+    }
+
+    #[test]
+    fn test_debugger() {
+        let instructions = parse_instructions("inc a\njio a, +2\ntpl a\ninc b\ninc a").unwrap();
+        let mut debugger = Debugger::new(&instructions, Runtime::new(), MAX_STEPS);
+
+        assert_eq!(
+            debugger.execute(DebugCommand::Step(2)),
+            "    3  inc b\n      a=1  b=0  (step 2)\n"
+        );
+        debugger.execute(DebugCommand::Break(4));
+        assert_eq!(
+            debugger.execute(DebugCommand::Continue),
+            "Breakpoint at instruction 4\n*   4  inc a\n      a=1  b=1  (step 3)\n"
+        );
+
+        debugger.execute(DebugCommand::Reverse(2));
+        assert_eq!(debugger.runtime().pc(), 1);
+        assert!(debugger
+            .execute(DebugCommand::Reverse(5))
+            .starts_with("No earlier state in the history\n"));
+        assert_eq!(debugger.runtime(), &Runtime::new());
+
+        debugger.execute(DebugCommand::Delete(4));
+        debugger.execute(DebugCommand::Watch('b'));
+        assert!(debugger
+            .execute(DebugCommand::Continue)
+            .starts_with("Register b changed from 0 to 1\n    4  inc a"));
+        assert!(debugger
+            .execute(DebugCommand::Continue)
+            .starts_with("Program has halted\n    5  halt"));
+    }
+
+    #[test]
+    fn test_debugger_undo() {
+        let instructions = parse_instructions("inc a\ntpl a\ninc a\nhlf a\njmp -4").unwrap();
+        let mut debugger = Debugger::new(&instructions, Runtime::new(), MAX_STEPS);
+
+        debugger.execute(DebugCommand::Step(3));
+        let before = debugger.runtime().clone();
+        debugger.execute(DebugCommand::Step(2));
+        assert_eq!(debugger.runtime().get_register('a'), 2);
+        assert_eq!(debugger.runtime().pc(), 0);
+
+        debugger.execute(DebugCommand::Reverse(2));
+        assert_eq!(debugger.runtime(), &before);
+        assert_eq!(before.get_register('a'), 4);
+    }
+}
@@ -0,0 +1,80 @@
+use aoc_core::{
+    bitset::DynBitSet,
+    parse::{integer, parse_lines, parse_with},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_packages(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(packages: &[usize]) -> Option<usize> {
+    get_lowest_entanglement(packages, 3)
+}
+
+fn part_two(packages: &[usize]) -> Option<usize> {
+    get_lowest_entanglement(packages, 4)
+}
+
+fn parse_packages(data: &str) -> Result<Vec<usize>, ParseError> {
+    parse_lines(data, |line| parse_with(line, integer, "package weight"))
+}
+
+fn get_lowest_entanglement(packages: &[usize], num_groups: usize) -> Option<usize> {
+    let total_weight = packages.iter().sum::<usize>();
+    if !total_weight.is_multiple_of(num_groups) {
+        return None;
+    }
+
+    let all_packages = DynBitSet::universe(packages.len());
+
+    for num in 1..packages.len() {
+        let groups = all_packages.subsets_of_size(num);
+        let correct_groups = groups.filter(|group| {
+            get_weights(group, packages).sum::<usize>() == total_weight / num_groups
+        });
+
+        if let Some(lowest_entanglement) = correct_groups
+            .map(|group| get_weights(&group, packages).product::<usize>())
+            .min()
+        {
+            return Some(lowest_entanglement);
+        }
+    }
+
+    None
+}
+
+fn get_weights<'a>(
+    group: &'a DynBitSet,
+    packages: &'a [usize],
+) -> impl Iterator<Item = usize> + 'a {
+    group.iter().map(|index| packages[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_many_packages() {
+        let mut packages = vec![1; 70];
+        packages.push(35);
+        assert_eq!(get_lowest_entanglement(&packages, 3), Some(35));
+        assert_eq!(get_lowest_entanglement(&packages, 4), None);
+    }
+}
@@ -0,0 +1,71 @@
+use aoc_core::{
+    math::mod_pow,
+    parse::{numbers, single_line},
+    Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = (usize, usize);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_position(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(*input).into()
+    }
+}
+
+fn part_one((row, column): (usize, usize)) -> Option<u64> {
+    let index = get_index(row, column);
+
+    // Every next code multiplies the previous one by the same factor
+    let (first, factor, modulus) = (20151125, 252533, 33554393);
+    Some(first * mod_pow(factor, index as u64 - 1, modulus)? % modulus)
+}
+
+/// The row and column of the code, both counted from one
+fn parse_position(data: &str) -> Result<(usize, usize), ParseError> {
+    let line = single_line(data, "row and column")?;
+    numbers(line)
+        .map_err(|err| err.relative_to(data, line))?
+        .into_iter()
+        .collect_tuple()
+        .filter(|&(row, column)| row > 0 && column > 0)
+        .ok_or_else(|| ParseError::new(data, line, "row and column"))
+}
+
+fn get_index(row: usize, column: usize) -> usize {
+    let total = row + column - 2;
+    total * (total + 1) / 2 + column
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index() {
+        assert_eq!(get_index(1, 1), 1);
+        assert_eq!(get_index(2, 4), 14);
+        assert_eq!(get_index(4, 3), 18);
+    }
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one((1, 1)), Some(20151125));
+        assert_eq!(part_one((4, 3)), Some(21345942));
+        assert_eq!(part_one((6, 6)), Some(27995004));
+    }
+
+    #[test]
+    fn test_parse_position() {
+        let data = "Enter the code at row 2981, column 3075.";
+        assert_eq!(parse_position(data), Ok((2981, 3075)));
+        assert!(parse_position("Enter the code at row 0, column 1.").is_err());
+        assert!(parse_position("Enter the code at row 1.").is_err());
+    }
+}
@@ -0,0 +1,64 @@
+use aoc_core::{
+    parse::{integer, parse_lines, parse_with},
+    Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_depths(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(depths: &[usize]) -> usize {
+    depths
+        .iter()
+        .tuple_windows()
+        .filter(|(previous, current)| previous < current)
+        .count()
+}
+
+fn part_two(depths: &[usize]) -> usize {
+    depths
+        .iter()
+        .tuple_windows::<(_, _, _)>()
+        .map(|(first, second, third)| first + second + third)
+        .tuple_windows()
+        .filter(|(previous, current)| previous < current)
+        .count()
+}
+
+fn parse_depths(data: &str) -> Result<Vec<usize>, ParseError> {
+    parse_lines(data, |line| parse_with(line, integer, "depth"))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2021, 1, 1);
+        assert_eq!(7, part_one(&parse_depths(&data).unwrap()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2021, 1, 1);
+        assert_eq!(5, part_two(&parse_depths(&data).unwrap()));
+    }
+}
@@ -0,0 +1,124 @@
+use aoc_core::{parse::parse_lines, Answer, ParseError, Solution};
+
+#[derive(Default)]
+struct Position {
+    depth: usize,
+    horizontal: usize,
+    aim: usize,
+}
+
+impl Position {
+    fn parse_move(&mut self, movement: &Move) {
+        match movement {
+            Move::Up(amount) => self.depth -= amount,
+            Move::Down(amount) => self.depth += amount,
+            Move::Forward(amount) => self.horizontal += amount,
+        }
+    }
+
+    fn parse_complicated_move(&mut self, movement: &Move) {
+        match movement {
+            Move::Up(amount) => self.aim -= amount,
+            Move::Down(amount) => self.aim += amount,
+            Move::Forward(amount) => {
+                self.horizontal += amount;
+                self.depth += self.aim * amount;
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Move {
+    Up(usize),
+    Down(usize),
+    Forward(usize),
+}
+
+impl TryFrom<&str> for Move {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (direction, amount) = value
+            .split_once(' ')
+            .ok_or_else(|| ParseError::missing(value, "space followed by an amount"))?;
+        let amount = amount
+            .parse::<usize>()
+            .map_err(|_| ParseError::new(value, amount, "amount"))?;
+        match direction {
+            "up" => Ok(Move::Up(amount)),
+            "down" => Ok(Move::Down(amount)),
+            "forward" => Ok(Move::Forward(amount)),
+            _ => Err(ParseError::new(value, direction, "up, down or forward")),
+        }
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Move>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_moves(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(moves: &[Move]) -> usize {
+    let mut position = Position::default();
+    moves.iter().for_each(|movement| {
+        position.parse_move(movement);
+    });
+    position.horizontal * position.depth
+}
+
+fn part_two(moves: &[Move]) -> usize {
+    let mut position = Position::default();
+    moves.iter().for_each(|movement| {
+        position.parse_complicated_move(movement);
+    });
+    position.horizontal * position.depth
+}
+
+fn parse_moves(data: &str) -> Result<Vec<Move>, ParseError> {
+    parse_lines(data, Move::try_from)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2021, 2, 1);
+        let moves = parse_moves(&data).unwrap();
+        assert_eq!(150, part_one(&moves));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2021, 2, 1);
+        let moves = parse_moves(&data).unwrap();
+        assert_eq!(900, part_two(&moves));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_moves("forward 5\nbackward 3").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "up, down or forward");
+
+        let err = parse_moves("down x").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 6, "x"));
+    }
+}
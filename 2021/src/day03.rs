@@ -0,0 +1,140 @@
+use aoc_core::{
+    parse::{parse_chars, parse_lines},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Vec<bool>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_report(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(report: &[Vec<bool>]) -> usize {
+    let line_len = report[0].len();
+    let char_sum = report.iter().fold(vec![0; line_len], |acc, cur| {
+        (0..line_len)
+            .map(|index| acc[index] + get_bit(cur[index]))
+            .collect()
+    });
+
+    let gamma_rate = char_sum.iter().map(|x| *x >= 0).collect::<Vec<_>>();
+    let epsilon_rate = gamma_rate.iter().map(|x| !x).collect::<Vec<_>>();
+
+    get_decimal(&gamma_rate) * get_decimal(&epsilon_rate)
+}
+
+fn part_two(report: &[Vec<bool>]) -> usize {
+    let ox_gen = get_rating(report, true);
+    let co2_scrub = get_rating(report, false);
+
+    get_decimal(ox_gen) * get_decimal(co2_scrub)
+}
+
+/// Filters the numbers bit by bit, keeping those with the most or least common bit
+/// until one number remains
+fn get_rating(report: &[Vec<bool>], most_common: bool) -> &[bool] {
+    let mut options = report.iter().collect::<Vec<_>>();
+
+    let mut i = 0;
+    while options.len() > 1 && i < report[0].len() {
+        let total = options
+            .iter()
+            .map(|option| get_bit(option[i]))
+            .sum::<isize>();
+        let bit = (total >= 0) == most_common;
+        if options.iter().any(|option| option[i] == bit) {
+            options.retain(|option| option[i] == bit);
+        }
+        i += 1;
+    }
+
+    options[0]
+}
+
+fn get_bit(bit: bool) -> isize {
+    if bit {
+        1
+    } else {
+        -1
+    }
+}
+
+fn get_decimal(bits: &[bool]) -> usize {
+    let mut total = 0;
+    for &bit in bits {
+        total *= 2;
+        total += usize::from(bit);
+    }
+    total
+}
+
+/// Binary numbers that all have the same number of bits
+fn parse_report(data: &str) -> Result<Vec<Vec<bool>>, ParseError> {
+    let mut width = None;
+    let report = parse_lines(data, |line| {
+        let bits = parse_chars(
+            line,
+            |chr| match chr {
+                '0' => Some(false),
+                '1' => Some(true),
+                _ => None,
+            },
+            "0 or 1",
+        )?;
+        if bits.is_empty() {
+            return Err(ParseError::missing(line, "binary number"));
+        }
+
+        let width = *width.get_or_insert(bits.len());
+        if bits.len() == width {
+            Ok(bits)
+        } else {
+            Err(ParseError::new(line, line, format!("{width} bits")))
+        }
+    })?;
+
+    if report.is_empty() {
+        return Err(ParseError::missing(data, "binary number"));
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2021, 3, 1);
+        assert_eq!(198, part_one(&parse_report(&data).unwrap()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2021, 3, 1);
+        assert_eq!(230, part_two(&parse_report(&data).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_report("0101\n0121").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "2"));
+
+        let err = parse_report("0101\n010").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (2, "4 bits"));
+    }
+}
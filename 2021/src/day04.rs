@@ -1,6 +1,7 @@
-use std::fs::read_to_string;
+use aoc_core::{Answer, ParseError, Solution};
 
-struct Game {
+#[derive(Debug, Clone)]
+pub struct Game {
     values: Vec<usize>,
     boards: Vec<Board>,
 }
@@ -49,47 +50,47 @@ impl Board {
     }
 }
 
-fn main() {
-    let data = parse_data("data/04.txt");
+pub struct Day04;
 
-    let answer = part_one(&data);
-    println!("Part 1: {answer}");
+impl Solution for Day04 {
+    type Input<'a> = Game;
 
-    let answer = part_two(&data);
-    println!("Part 2: {answer}");
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
 
-fn part_one(data: &str) -> usize {
-    let game = parse_input(data).expect("Could not create game with data");
-    let mut winning_board =
-        play_game(game, Objective::Win).expect("Could not find winning board for game");
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
 
-    winning_board.get_score()
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
 }
 
-fn part_two(data: &str) -> usize {
-    let game = parse_input(data).expect("Could not create game with data");
-    let mut losing_board =
-        play_game(game, Objective::Lose).expect("Could not find losing board for game");
+fn part_one(game: &Game) -> Option<usize> {
+    let mut winning_board = play_game(game.clone(), Objective::Win)?;
 
-    losing_board.get_score()
+    Some(winning_board.get_score())
 }
 
-fn parse_data(filename: &str) -> String {
-    read_to_string(filename).expect("Could not read datafile")
+fn part_two(game: &Game) -> Option<usize> {
+    let mut losing_board = play_game(game.clone(), Objective::Lose)?;
+
+    Some(losing_board.get_score())
 }
 
-fn parse_input(data: &str) -> Result<Game, Box<dyn std::error::Error>> {
+fn parse_input(data: &str) -> Result<Game, ParseError> {
     let mut lines = data.lines();
 
     let values = lines
         .next()
-        .unwrap()
+        .ok_or_else(|| ParseError::missing(data, "drawn numbers"))?
         .split(',')
-        .map(|number| number.parse::<usize>().unwrap())
-        .collect::<Vec<_>>();
+        .map(|number| parse_number(data, number))
+        .collect::<Result<Vec<_>, _>>()?;
 
-    let _ = lines.next().unwrap();
+    let _ = lines.next();
 
     let mut size = 0;
     let mut boards = Vec::new();
@@ -108,8 +109,8 @@ fn parse_input(data: &str) -> Result<Game, Box<dyn std::error::Error>> {
 
         board.values.append(
             numbers
-                .map(|number| number.parse::<usize>().unwrap())
-                .collect::<Vec<_>>()
+                .map(|number| parse_number(data, number))
+                .collect::<Result<Vec<_>, _>>()?
                 .as_mut(),
         );
     }
@@ -119,6 +120,12 @@ fn parse_input(data: &str) -> Result<Game, Box<dyn std::error::Error>> {
     Ok(Game { values, boards })
 }
 
+fn parse_number(data: &str, number: &str) -> Result<usize, ParseError> {
+    number
+        .parse::<usize>()
+        .map_err(|_| ParseError::new(data, number, "number"))
+}
+
 fn play_game(mut game: Game, objective: Objective) -> Option<Board> {
     let mut winning_boards = Vec::new();
     let num_boards = game.boards.len();
@@ -152,17 +159,34 @@ fn play_game(mut game: Game, objective: Objective) -> Option<Board> {
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::read_example;
+
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let data = parse_data("data/04-example.txt");
-        assert_eq!(4512, part_one(&data));
+        let data = read_example(2021, 4, 1);
+        let game = parse_input(&data).unwrap();
+        assert_eq!(Some(4512), part_one(&game));
     }
 
     #[test]
     fn test_part_two() {
-        let data = parse_data("data/04-example.txt");
-        assert_eq!(1924, part_two(&data));
+        let data = read_example(2021, 4, 1);
+        let game = parse_input(&data).unwrap();
+        assert_eq!(Some(1924), part_two(&game));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("7,4,9\n\n22 13 17\n 8  x 23").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (4, 5, "x"));
+    }
+
+    #[test]
+    fn test_no_winner() {
+        let game = parse_input("1").unwrap();
+        assert_eq!(part_one(&game), None);
+        assert_eq!(part_two(&game), None);
     }
 }
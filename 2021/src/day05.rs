@@ -0,0 +1,114 @@
+use aoc_core::{
+    grid::{Coord, Edges, Grid},
+    parse::{integer, parse_lines, parse_with},
+    Answer, ParseError, Solution,
+};
+use nom::{bytes::complete::tag, character::complete::char, sequence::separated_pair};
+use std::iter::once;
+
+#[derive(PartialEq)]
+enum DiagonalStatus {
+    Enabled,
+    Disabled,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Vec<(Coord, Coord)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_vents(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(vents: &[(Coord, Coord)]) -> usize {
+    count_overlaps(vents, DiagonalStatus::Disabled)
+}
+
+fn part_two(vents: &[(Coord, Coord)]) -> usize {
+    count_overlaps(vents, DiagonalStatus::Enabled)
+}
+
+fn count_overlaps(vents: &[(Coord, Coord)], diagonal: DiagonalStatus) -> usize {
+    // The ocean only needs to reach as far as the furthest vent
+    let points = vents.iter().flat_map(|&(from, to)| [from, to]);
+    let width = points.clone().map(|(x, _)| x + 1).max().unwrap_or(0);
+    let height = points.map(|(_, y)| y + 1).max().unwrap_or(0);
+    let mut ocean = Grid::new(width, height, 0);
+
+    vents
+        .iter()
+        .filter_map(|&(from, to)| get_step(from, to, &diagonal).map(|step| (from, to, step)))
+        .for_each(|(from, to, step)| {
+            let length = usize::max(from.0.abs_diff(to.0), from.1.abs_diff(to.1));
+            let line = once(from).chain(ocean.ray(from, step, Edges::Bounded).take(length));
+            for coord in line.collect::<Vec<_>>() {
+                ocean[coord] += 1;
+            }
+        });
+
+    ocean.values().filter(|&&val| val >= 2).count()
+}
+
+fn parse_vents(data: &str) -> Result<Vec<(Coord, Coord)>, ParseError> {
+    parse_lines(data, |line| {
+        let point = || separated_pair(integer, char(','), integer);
+        parse_with(
+            line,
+            separated_pair(point(), tag(" -> "), point()),
+            "line of vents such as \"0,9 -> 5,9\"",
+        )
+    })
+}
+
+/// Offset between consecutive points of a line, if the line is taken into account
+fn get_step(from: Coord, to: Coord, diagonal: &DiagonalStatus) -> Option<Coord<isize>> {
+    let step = (
+        (to.0 as isize - from.0 as isize).signum(),
+        (to.1 as isize - from.1 as isize).signum(),
+    );
+
+    (step.0 == 0 || step.1 == 0 || *diagonal == DiagonalStatus::Enabled).then_some(step)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2021, 5, 1);
+        let vents = parse_vents(&data).unwrap();
+        assert_eq!(5, part_one(&vents));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2021, 5, 1);
+        let vents = parse_vents(&data).unwrap();
+        assert_eq!(12, part_two(&vents));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_vents("0,9 -> 5,9\n8,0 => 0,8").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, " "));
+    }
+
+    #[test]
+    fn test_large_coordinates() {
+        let vents = parse_vents("0,1500 -> 2000,1500\n1000,0 -> 1000,3000").unwrap();
+        assert_eq!(1, part_one(&vents));
+    }
+}
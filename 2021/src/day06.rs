@@ -0,0 +1,99 @@
+use aoc_core::{
+    parse::{comma_list, integer, parse_with},
+    Answer, ParseError, Solution,
+};
+use nom::combinator::verify;
+
+const MAX_AGE: usize = 8;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_ages(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(ages: &[usize]) -> usize {
+    let mut lanternfish = get_lanternfish(ages);
+
+    for _day in 0..80 {
+        lanternfish = shift_day(lanternfish);
+    }
+
+    lanternfish.into_iter().sum::<usize>()
+}
+
+fn part_two(ages: &[usize]) -> usize {
+    let mut lanternfish = get_lanternfish(ages);
+
+    for _day in 0..256 {
+        lanternfish = shift_day(lanternfish);
+    }
+
+    lanternfish.into_iter().sum::<usize>()
+}
+
+fn shift_day(mut lanternfish: [usize; MAX_AGE + 1]) -> [usize; MAX_AGE + 1] {
+    let new_lanternfish = lanternfish[0];
+    for age in 0..(MAX_AGE) {
+        lanternfish[age] = lanternfish[age + 1];
+    }
+    lanternfish[MAX_AGE] = new_lanternfish;
+    lanternfish[MAX_AGE - 2] += new_lanternfish;
+
+    lanternfish
+}
+
+/// Number of lanternfish of every age
+fn get_lanternfish(ages: &[usize]) -> [usize; MAX_AGE + 1] {
+    ages.iter().fold([0; MAX_AGE + 1], |mut acc, &age| {
+        acc[age] += 1;
+        acc
+    })
+}
+
+fn parse_ages(data: &str) -> Result<Vec<usize>, ParseError> {
+    parse_with(
+        data,
+        comma_list(verify(integer, |&age: &usize| age <= MAX_AGE)),
+        &format!("age of at most {MAX_AGE}"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2021, 6, 1);
+        let ages = parse_ages(&data).unwrap();
+        assert_eq!(5934, part_one(&ages));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2021, 6, 1);
+        let ages = parse_ages(&data).unwrap();
+        assert_eq!(26984457539, part_two(&ages));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_ages("3,4,9,1\n").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (5, "9,1"));
+    }
+}
@@ -1,17 +1,27 @@
-use std::fs::read_to_string;
+use aoc_core::{
+    parse::{comma_list, integer, parse_with},
+    Answer, ParseError, Solution,
+};
 
-fn main() {
-    let data = parse_data("data/07.txt");
+pub struct Day07;
 
-    let answer = part_one(&data);
-    println!("Part 1: {answer}");
+impl Solution for Day07 {
+    type Input<'a> = Vec<isize>;
 
-    let answer = part_two(&data);
-    println!("Part 2: {answer}");
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_positions(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
 }
 
-fn part_one(data: &str) -> usize {
-    let crabs = get_positions(data).collect::<Vec<_>>();
+fn part_one(crabs: &[isize]) -> usize {
     let min = *crabs.iter().min().expect("Could not find min position");
     let max = *crabs.iter().max().expect("Could not find max position");
 
@@ -26,8 +36,7 @@ fn part_one(data: &str) -> usize {
         .expect("Could not find optimal position")
 }
 
-fn part_two(data: &str) -> usize {
-    let crabs = get_positions(data).collect::<Vec<_>>();
+fn part_two(crabs: &[isize]) -> usize {
     let min = *crabs.iter().min().expect("Could not find min position");
     let max = *crabs.iter().max().expect("Could not find max position");
 
@@ -45,32 +54,27 @@ fn part_two(data: &str) -> usize {
         .expect("Could not find optimal position")
 }
 
-fn get_positions(input: &str) -> impl Iterator<Item = isize> + '_ {
-    input
-        .lines()
-        .next()
-        .expect("Datafile was emtpy")
-        .split(',')
-        .map(|position| position.parse().unwrap())
-}
-
-fn parse_data(filename: &str) -> String {
-    read_to_string(filename).expect("Could not read datafile")
+fn parse_positions(data: &str) -> Result<Vec<isize>, ParseError> {
+    parse_with(data, comma_list(integer), "position")
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::read_example;
+
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let data = parse_data("data/07-example.txt");
-        assert_eq!(part_one(&data), 37);
+        let data = read_example(2021, 7, 1);
+        let crabs = parse_positions(&data).unwrap();
+        assert_eq!(part_one(&crabs), 37);
     }
 
     #[test]
     fn test_part_two() {
-        let data = parse_data("data/07-example.txt");
-        assert_eq!(part_two(&data), 168);
+        let data = read_example(2021, 7, 1);
+        let crabs = parse_positions(&data).unwrap();
+        assert_eq!(part_two(&crabs), 168);
     }
 }
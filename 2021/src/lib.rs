@@ -0,0 +1,19 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+
+use aoc_core::Day;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(1),
+    Day::new::<day02::Day02>(2),
+    Day::new::<day03::Day03>(3),
+    Day::new::<day04::Day04>(4),
+    Day::new::<day05::Day05>(5),
+    Day::new::<day06::Day06>(6),
+    Day::new::<day07::Day07>(7),
+];
@@ -0,0 +1,66 @@
+use aoc_core::{
+    parse::{integer, parse_blocks, parse_lines, parse_with},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<Vec<usize>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_elves(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(elves: &[Vec<usize>]) -> usize {
+    elves
+        .iter()
+        .map(|elf| elf.iter().sum::<usize>())
+        .max()
+        .unwrap_or(0)
+}
+
+fn part_two(elves: &[Vec<usize>]) -> usize {
+    let mut totals = elves
+        .iter()
+        .map(|elf| elf.iter().sum::<usize>())
+        .collect::<Vec<_>>();
+
+    totals.sort();
+    totals.iter().rev().take(3).sum::<usize>()
+}
+
+/// The calories of every item each elf carries, with a blank line between elves
+fn parse_elves(data: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+    parse_blocks(data, |elf| {
+        parse_lines(elf, |line| parse_with(line, integer, "calories"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 1, 1);
+        assert_eq!(24000, part_one(&parse_elves(&data).unwrap()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 1, 1);
+        assert_eq!(45000, part_two(&parse_elves(&data).unwrap()));
+    }
+}
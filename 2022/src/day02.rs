@@ -0,0 +1,192 @@
+use aoc_core::{parse::parse_lines, Answer, ParseError, Solution};
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl TryFrom<&str> for Shape {
+    type Error = ParseError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "A" | "X" => Ok(Shape::Rock),
+            "B" | "Y" => Ok(Shape::Paper),
+            "C" | "Z" => Ok(Shape::Scissors),
+            _ => Err(ParseError::new(value, value, "shape")),
+        }
+    }
+}
+
+impl Ord for Shape {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self.get_score() as isize - other.get_score() as isize).rem_euclid(3) {
+            0 => std::cmp::Ordering::Equal,
+            1 => std::cmp::Ordering::Greater,
+            2 => std::cmp::Ordering::Less,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl PartialOrd for Shape {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Shape {
+    fn get_score(&self) -> usize {
+        match &self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+
+    fn get_shape(index: usize) -> Self {
+        match index % 3 {
+            0 => Shape::Rock,
+            1 => Shape::Paper,
+            2 => Shape::Scissors,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Instruction {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = ParseError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "X" => Ok(Instruction::Lose),
+            "Y" => Ok(Instruction::Draw),
+            "Z" => Ok(Instruction::Win),
+            _ => Err(ParseError::new(value, value, "X, Y or Z")),
+        }
+    }
+}
+
+impl Instruction {
+    fn get_offset(&self) -> usize {
+        match &self {
+            Instruction::Lose => 2,
+            Instruction::Draw => 0,
+            Instruction::Win => 1,
+        }
+    }
+
+    fn get_score(&self) -> usize {
+        match &self {
+            Instruction::Lose => 0,
+            Instruction::Draw => 3,
+            Instruction::Win => 6,
+        }
+    }
+}
+
+/// Line of the strategy guide, with the second column read both as a shape and as an instruction
+pub struct Round {
+    opponent: Shape,
+    player: Shape,
+    instruction: Instruction,
+}
+
+impl TryFrom<&str> for Round {
+    type Error = ParseError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (opponent, player) = value
+            .split_once(' ')
+            .ok_or_else(|| ParseError::missing(value, "space followed by a response"))?;
+        let response = |err: ParseError| err.relative_to(value, player);
+        Ok(Round {
+            opponent: opponent.try_into()?,
+            player: player.try_into().map_err(response)?,
+            instruction: player.try_into().map_err(response)?,
+        })
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_lines(input, Round::try_from)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(rounds: &[Round]) -> usize {
+    rounds
+        .iter()
+        .map(|round| round.player.get_score() + get_match_score(round.opponent, round.player))
+        .sum::<usize>()
+}
+
+fn part_two(rounds: &[Round]) -> usize {
+    rounds
+        .iter()
+        .map(|round| {
+            let player =
+                Shape::get_shape(round.opponent.get_score() - 1 + round.instruction.get_offset());
+            player.get_score() + round.instruction.get_score()
+        })
+        .sum::<usize>()
+}
+
+fn get_match_score(opponent_shape: Shape, player_shape: Shape) -> usize {
+    match player_shape.cmp(&opponent_shape) {
+        std::cmp::Ordering::Less => 0,
+        std::cmp::Ordering::Equal => 3,
+        std::cmp::Ordering::Greater => 6,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 2, 1);
+        let rounds = parse_lines(&data, Round::try_from).unwrap();
+        assert_eq!(15, part_one(&rounds));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 2, 1);
+        let rounds = parse_lines(&data, Round::try_from).unwrap();
+        assert_eq!(12, part_two(&rounds));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let Err(err) = parse_lines("A Y\nB W", Round::try_from) else {
+            panic!("Parsed invalid response");
+        };
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected shape, found \"W\""
+        );
+    }
+}
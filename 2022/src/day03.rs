@@ -0,0 +1,105 @@
+use aoc_core::{
+    parse::{all_chars, parse_lines},
+    Answer, ParseError, Solution,
+};
+use std::collections::HashSet;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_rucksacks(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(rucksacks: &[&str]) -> Option<usize> {
+    rucksacks
+        .iter()
+        .map(|line| {
+            let (first, second) = get_compartments(line);
+            first.intersection(&second).next().copied()
+        })
+        .map(|item| item.map(get_priority))
+        .sum::<Option<usize>>()
+}
+
+fn part_two(rucksacks: &[&str]) -> Option<usize> {
+    if !rucksacks.len().is_multiple_of(3) {
+        return None;
+    }
+
+    rucksacks
+        .chunks(3)
+        .map(|group| {
+            group
+                .iter()
+                .map(|line| line.chars().collect::<HashSet<_>>())
+                .reduce(|left, right| left.intersection(&right).copied().collect::<HashSet<_>>())?
+                .into_iter()
+                .next()
+        })
+        .map(|item| item.map(get_priority))
+        .sum::<Option<usize>>()
+}
+
+fn get_compartments(line: &str) -> (HashSet<char>, HashSet<char>) {
+    let num_items = line.len();
+    let mut characters = line.chars();
+
+    let first = characters
+        .by_ref()
+        .take(num_items / 2)
+        .collect::<HashSet<_>>();
+    let second = characters.collect::<HashSet<_>>();
+
+    (first, second)
+}
+
+fn get_priority(character: char) -> usize {
+    (if character.is_uppercase() {
+        character as u8 - b'A' + 27
+    } else {
+        character as u8 - b'a' + 1
+    }) as usize
+}
+
+/// Rucksacks of letters, which are split into two equally sized compartments
+fn parse_rucksacks(data: &str) -> Result<Vec<&str>, ParseError> {
+    parse_lines(data, |line| {
+        let items = all_chars(line, |chr| chr.is_ascii_alphabetic(), "item")?;
+        if items.len().is_multiple_of(2) {
+            Ok(items)
+        } else {
+            Err(ParseError::new(line, line, "even number of items"))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 3, 1);
+        assert_eq!(Some(157), part_one(&parse_rucksacks(&data).unwrap()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 3, 1);
+        assert_eq!(Some(70), part_two(&parse_rucksacks(&data).unwrap()));
+    }
+}
@@ -0,0 +1,109 @@
+use std::ops::RangeInclusive;
+
+use aoc_core::{interval::IntervalSet, parse::parse_lines, Answer, ParseError, Solution};
+
+type Range = RangeInclusive<usize>;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Vec<(Range, Range)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_lines(input, parse_line)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(pairs: &[(Range, Range)]) -> usize {
+    pairs
+        .iter()
+        .map(|(range1, range2)| {
+            (
+                IntervalSet::from(range1.clone()),
+                IntervalSet::from(range2.clone()),
+            )
+        })
+        .filter(|(sections1, sections2)| {
+            let intersection = sections1.intersection(sections2);
+            intersection == *sections1 || intersection == *sections2
+        })
+        .count()
+}
+
+fn part_two(pairs: &[(Range, Range)]) -> usize {
+    pairs
+        .iter()
+        .map(|(range1, range2)| {
+            (
+                IntervalSet::from(range1.clone()),
+                IntervalSet::from(range2.clone()),
+            )
+        })
+        .filter(|(sections1, sections2)| !sections1.intersection(sections2).is_empty())
+        .count()
+}
+
+fn parse_line(line: &str) -> Result<(Range, Range), ParseError> {
+    let (range1, range2) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::missing(line, "\",\" followed by a range"))?;
+    Ok((parse_range(line, range1)?, parse_range(line, range2)?))
+}
+
+fn parse_range(line: &str, range_str: &str) -> Result<Range, ParseError> {
+    let (lower_bound, upper_bound) = range_str
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(line, range_str, "range such as 2-4"))?;
+    let parse_bound = |bound: &str| {
+        bound
+            .parse::<usize>()
+            .map_err(|_| ParseError::new(line, bound, "section number"))
+    };
+    Ok(parse_bound(lower_bound)?..=parse_bound(upper_bound)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 4, 1);
+        let pairs = parse_lines(&data, parse_line).unwrap();
+        assert_eq!(2, part_one(&pairs));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 4, 1);
+        let pairs = parse_lines(&data, parse_line).unwrap();
+        assert_eq!(4, part_two(&pairs));
+    }
+
+    #[test]
+    fn test_large_sections() {
+        let pairs = parse_lines("100-200,150-180\n5-300,301-400", parse_line).unwrap();
+        assert_eq!(part_one(&pairs), 1);
+        assert_eq!(part_two(&pairs), 1);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_lines("2-4,6-8\n2-3,45", parse_line).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 5, "45"));
+
+        let err = parse_lines("2-4,6-b", parse_line).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+        assert_eq!(err.expected, "section number");
+    }
+}
@@ -0,0 +1,159 @@
+use aoc_core::{Answer, ParseError, Solution};
+use itertools::Itertools;
+use std::collections::VecDeque;
+
+type Crate = char;
+type Stack = VecDeque<Crate>;
+
+#[derive(Debug)]
+pub struct Instruction {
+    amount: usize,
+    from: usize,
+    to: usize,
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let mut words = line.split_whitespace();
+
+        let mut parse_field = |keyword: &str, name: &str| {
+            match words.next() {
+                Some(word) if word == keyword => {}
+                Some(word) => return Err(ParseError::new(line, word, format!("\"{keyword}\""))),
+                None => return Err(ParseError::missing(line, format!("\"{keyword}\""))),
+            }
+            let number = words
+                .next()
+                .ok_or_else(|| ParseError::missing(line, name))?;
+            number
+                .parse()
+                .map_err(|_| ParseError::new(line, number, name))
+        };
+
+        let amount = parse_field("move", "number of crates")?;
+        let from = parse_field("from", "start stack")?;
+        let to = parse_field("to", "end stack")?;
+
+        Ok(Instruction { amount, from, to })
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = (Vec<Stack>, Vec<Instruction>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((stacks, instructions): &Self::Input<'_>) -> Answer {
+        part_one(stacks.clone(), instructions).into()
+    }
+
+    fn part_two((stacks, instructions): &Self::Input<'_>) -> Answer {
+        part_two(stacks.clone(), instructions).into()
+    }
+}
+
+fn part_one(mut stacks: Vec<Stack>, instructions: &[Instruction]) -> String {
+    for instruction in instructions {
+        let stack = &mut stacks[instruction.from - 1];
+        let moved = stack.split_off(stack.len() - (instruction.amount));
+        stacks[instruction.to - 1].extend(moved.into_iter().rev())
+    }
+
+    stacks
+        .into_iter()
+        .filter_map(|mut stack| stack.pop_back())
+        .collect()
+}
+
+fn part_two(mut stacks: Vec<Stack>, instructions: &[Instruction]) -> String {
+    for instruction in instructions {
+        let stack = &mut stacks[instruction.from - 1];
+        let moved = stack.split_off(stack.len() - (instruction.amount));
+        stacks[instruction.to - 1].extend(moved)
+    }
+
+    stacks
+        .into_iter()
+        .filter_map(|mut stack| stack.pop_back())
+        .collect()
+}
+
+fn parse_input(data: &str) -> Result<(Vec<Stack>, Vec<Instruction>), ParseError> {
+    let num_stacks = data
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::missing(data, "drawing of the stacks"))?
+        .len()
+        / 3;
+    let mut stacks: Vec<Stack> = vec![VecDeque::new(); num_stacks];
+
+    let mut lines = data.lines();
+
+    lines
+        .by_ref()
+        .take_while(|line| !line.is_empty())
+        .try_for_each(|line| {
+            let chunk = line.chars().chunks(4);
+            chunk
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, mut chunk)| {
+                    let character = chunk.nth(1)?;
+                    (character != ' ').then_some((index, character))
+                })
+                .try_for_each(|(index, character)| {
+                    let stack = stacks.get_mut(index).ok_or_else(|| {
+                        let text = line.get(index * 4 + 1..index * 4 + 2).unwrap_or(line);
+                        ParseError::new(data, text, format!("crate in one of {num_stacks} stacks"))
+                    })?;
+                    stack.push_front(character);
+                    Ok(())
+                })
+        })?;
+
+    // Remove stack number from stack
+    stacks.iter_mut().for_each(|stack| {
+        let _ = stack.pop_front();
+    });
+
+    let instructions = lines
+        .map(|line| Instruction::try_from(line).map_err(|err| err.relative_to(data, line)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((stacks, instructions))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 5, 1);
+        let (stacks, instructions) = parse_input(&data).unwrap();
+        assert_eq!("CMZ", &part_one(stacks, &instructions));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 5, 1);
+        let (stacks, instructions) = parse_input(&data).unwrap();
+        assert_eq!("MCD", &part_two(stacks, &instructions));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let data = read_example(2022, 5, 1).replace("from 2 to 1", "from 2 onto 1");
+        let err = parse_input(&data).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (6, 15, "onto"));
+        assert_eq!(err.expected, "\"to\"");
+    }
+}
@@ -0,0 +1,94 @@
+use aoc_core::{
+    parse::{all_chars, single_line},
+    Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+use std::hash::Hash;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_characters(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(characters: &[char]) -> Option<usize> {
+    get_unique_sequence_index(characters, 4)
+}
+
+fn part_two(characters: &[char]) -> Option<usize> {
+    get_unique_sequence_index(characters, 14)
+}
+
+fn get_unique_sequence_index<T>(sequence: &[T], length: usize) -> Option<usize>
+where
+    T: Hash + Eq,
+{
+    let window_index = sequence
+        .windows(length)
+        .position(|window| window.iter().unique().count() == length)?;
+
+    Some(window_index + length)
+}
+
+fn parse_characters(data: &str) -> Result<Vec<char>, ParseError> {
+    let line = single_line(data, "datastream")?;
+    let datastream = all_chars(line, |chr| chr.is_ascii_lowercase(), "lowercase letter")
+        .map_err(|err| err.relative_to(data, line))?;
+
+    Ok(datastream.chars().collect::<Vec<_>>())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 6, 1);
+        assert_eq!(Some(7), part_one(&parse_characters(&data).unwrap()));
+
+        let data = read_example(2022, 6, 2);
+        assert_eq!(Some(5), part_one(&parse_characters(&data).unwrap()));
+
+        let data = read_example(2022, 6, 3);
+        assert_eq!(Some(6), part_one(&parse_characters(&data).unwrap()));
+
+        let data = read_example(2022, 6, 4);
+        assert_eq!(Some(10), part_one(&parse_characters(&data).unwrap()));
+
+        let data = read_example(2022, 6, 5);
+        assert_eq!(Some(11), part_one(&parse_characters(&data).unwrap()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 6, 1);
+        assert_eq!(Some(19), part_two(&parse_characters(&data).unwrap()));
+
+        let data = read_example(2022, 6, 2);
+        assert_eq!(Some(23), part_two(&parse_characters(&data).unwrap()));
+
+        let data = read_example(2022, 6, 3);
+        assert_eq!(Some(23), part_two(&parse_characters(&data).unwrap()));
+
+        let data = read_example(2022, 6, 4);
+        assert_eq!(Some(29), part_two(&parse_characters(&data).unwrap()));
+
+        let data = read_example(2022, 6, 5);
+        assert_eq!(Some(26), part_two(&parse_characters(&data).unwrap()));
+    }
+}
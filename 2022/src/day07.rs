@@ -0,0 +1,147 @@
+use aoc_core::{
+    parse::{integer, parse_lines, parse_with},
+    Answer, ParseError, Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while1},
+    character::complete::char,
+    combinator::{map, value},
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+pub struct FileEntry {
+    path: PathBuf,
+    size: usize,
+}
+
+#[derive(Clone)]
+enum Line<'a> {
+    Cd(&'a str),
+    Ls,
+    Dir,
+    File(usize, &'a str),
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<FileEntry>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_files(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(files: &[FileEntry]) -> usize {
+    let mut directories: HashMap<&Path, usize> = HashMap::new();
+    for file in files {
+        for ancestor in file.path.ancestors().skip(1) {
+            *directories.entry(ancestor).or_insert(0) += file.size
+        }
+    }
+
+    directories.values().filter(|&&size| size <= 100_000).sum()
+}
+
+fn part_two(files: &[FileEntry]) -> Option<usize> {
+    let total_size: usize = 70_000_000;
+    let needed: usize = 30_000_000;
+
+    let mut directories: HashMap<&Path, usize> = HashMap::new();
+    for file in files {
+        for ancestor in file.path.ancestors().skip(1) {
+            *directories.entry(ancestor).or_insert(0) += file.size
+        }
+    }
+
+    let used = directories.get(&Path::new("/"))?;
+    let free = total_size.saturating_sub(*used);
+
+    directories
+        .values()
+        .filter(|&&size| size >= needed.saturating_sub(free))
+        .min()
+        .copied()
+}
+
+/// Every file listed in the terminal output, with its path built from the `cd` commands before it
+fn parse_files(data: &str) -> Result<Vec<FileEntry>, ParseError> {
+    let lines = parse_lines(data, |line| {
+        parse_with(line, parse_line, "\"$ cd\", \"$ ls\" or directory listing")
+    })?;
+
+    let mut files: Vec<FileEntry> = Vec::new();
+    let mut current_path = PathBuf::new();
+
+    for line in lines {
+        match line {
+            Line::Cd("..") => {
+                current_path.pop();
+            }
+            Line::Cd("/") => current_path = PathBuf::from("/"),
+            Line::Cd(dir_name) => current_path.push(dir_name),
+            Line::Ls | Line::Dir => {}
+            Line::File(size, file_name) => files.push(FileEntry {
+                path: current_path.join(file_name),
+                size,
+            }),
+        }
+    }
+
+    Ok(files)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Line<'_>> {
+    let name = || take_while1(|chr: char| !chr.is_whitespace());
+
+    alt((
+        map(preceded(tag("$ cd "), name()), Line::Cd),
+        value(Line::Ls, tag("$ ls")),
+        value(Line::Dir, preceded(tag("dir "), name())),
+        map(
+            separated_pair(integer, char(' '), name()),
+            |(size, file_name)| Line::File(size, file_name),
+        ),
+    ))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 7, 1);
+        assert_eq!(95437, part_one(&parse_files(&data).unwrap()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 7, 1);
+        assert_eq!(Some(24933642), part_two(&parse_files(&data).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let Err(err) = parse_files("$ cd /\n$ rm a") else {
+            panic!("Parsed unknown command");
+        };
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "$"));
+    }
+}
@@ -0,0 +1,94 @@
+use aoc_core::{
+    grid::{Coord, Edges, Grid, ORTHOGONAL},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        get_heights(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(heights: &Grid<u32>) -> usize {
+    heights
+        .iter()
+        .filter(|&(coord, height)| {
+            ORTHOGONAL.into_iter().any(|direction| {
+                heights
+                    .ray(coord, direction, Edges::Bounded)
+                    .all(|tree| heights[tree] < *height)
+            })
+        })
+        .count()
+}
+
+fn part_two(heights: &Grid<u32>) -> usize {
+    heights
+        .iter()
+        .map(|(coord, _height)| {
+            ORTHOGONAL
+                .into_iter()
+                .map(|direction| get_viewing_distance(heights, coord, direction))
+                .product()
+        })
+        .max()
+        .expect("Could not find tree with optimal scenic score")
+}
+
+/// Number of trees that can be seen from `coord`, up to and including the first tree
+/// that is at least as tall
+fn get_viewing_distance(heights: &Grid<u32>, coord: Coord, direction: Coord<isize>) -> usize {
+    let height = heights[coord];
+    let mut distance = 0;
+    for tree in heights.ray(coord, direction, Edges::Bounded) {
+        distance += 1;
+        if heights[tree] >= height {
+            break;
+        }
+    }
+    distance
+}
+
+fn get_heights(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, "tree height", |character| character.to_digit(10))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 8, 1);
+        let heights = get_heights(&data).unwrap();
+        assert_eq!(part_one(&heights), 21);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 8, 1);
+        let heights = get_heights(&data).unwrap();
+        assert_eq!(part_two(&heights), 8);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = get_heights("303\n2-5").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "-"));
+        assert_eq!(err.expected, "tree height");
+    }
+}
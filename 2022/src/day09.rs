@@ -0,0 +1,172 @@
+use aoc_core::{
+    parse::parse_lines,
+    sparse::SparseGrid,
+    vector::{Direction, Vec2},
+    Answer, ParseError, Solution,
+};
+
+#[derive(Clone)]
+pub struct Instruction {
+    direction: Direction,
+    amount: usize,
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut parts = value.split_whitespace();
+        let direction = match parts
+            .next()
+            .ok_or_else(|| ParseError::missing(value, "direction"))?
+        {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            other => return Err(ParseError::new(value, other, "U, D, L or R")),
+        };
+
+        let amount = parts
+            .next()
+            .ok_or_else(|| ParseError::missing(value, "number of steps"))?;
+        let amount = amount
+            .parse::<usize>()
+            .map_err(|_| ParseError::new(value, amount, "number of steps"))?;
+
+        Ok(Instruction { direction, amount })
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_lines(input, Instruction::try_from)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(instructions: &[Instruction]) -> usize {
+    let rope_size = 2;
+
+    let instructions = instructions.iter().cloned().rev().collect::<Vec<_>>();
+
+    let positions = parse_instructions(instructions, rope_size);
+
+    let visited = positions
+        .last()
+        .unwrap()
+        .iter()
+        .map(|&coord| (coord.into(), ()))
+        .collect::<SparseGrid<()>>();
+
+    visited.len()
+}
+
+fn part_two(instructions: &[Instruction]) -> usize {
+    let rope_size = 10;
+
+    let instructions = instructions.iter().cloned().rev().collect::<Vec<_>>();
+
+    let positions = parse_instructions(instructions, rope_size);
+
+    let visited = positions
+        .last()
+        .unwrap()
+        .iter()
+        .map(|&coord| (coord.into(), ()))
+        .collect::<SparseGrid<()>>();
+
+    visited.len()
+}
+
+fn parse_instructions(
+    mut instructions: Vec<Instruction>,
+    rope_size: usize,
+) -> Vec<Vec<Vec2<isize>>> {
+    let mut rope_positions = vec![vec![Vec2::ZERO]; rope_size];
+
+    while let Some(instruction) = instructions.pop() {
+        // Move head
+        let mut new_head_coord =
+            *rope_positions[0].last().unwrap() + instruction.direction.to_vec();
+        rope_positions[0].push(new_head_coord);
+
+        // Check whether tail needs to move
+        for knot_positions in rope_positions.iter_mut().take(rope_size).skip(1) {
+            let mut new_tail_coord = *knot_positions.last().unwrap();
+            if new_tail_coord.chebyshev_distance(new_head_coord) > 1 {
+                // Move one step towards the head, diagonally if not in the same row or column
+                new_tail_coord += (new_head_coord - new_tail_coord).signum();
+                knot_positions.push(new_tail_coord);
+            }
+
+            // Current tail will be head for next item in rope
+            new_head_coord = new_tail_coord;
+        }
+
+        // Add instruction to queue if unfinished
+        if instruction.amount > 1 {
+            instructions.push(Instruction {
+                direction: instruction.direction,
+                amount: instruction.amount - 1,
+            });
+        }
+    }
+
+    rope_positions
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 9, 1);
+        let instructions = parse_lines(&data, Instruction::try_from).unwrap();
+        assert_eq!(part_one(&instructions), 13);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 9, 1);
+        let instructions = parse_lines(&data, Instruction::try_from).unwrap();
+        assert_eq!(part_two(&instructions), 1);
+    }
+
+    #[test]
+    fn test_part_two_large_example() {
+        let data = read_example(2022, 9, 2);
+        let instructions = parse_lines(&data, Instruction::try_from).unwrap();
+        assert_eq!(part_two(&instructions), 36);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let Err(err) = parse_lines("R 4\nU 4\nX 3", Instruction::try_from) else {
+            panic!("Parsed invalid direction");
+        };
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 1, "X"));
+
+        let Err(err) = parse_lines("R 4\nU -4", Instruction::try_from) else {
+            panic!("Parsed negative number of steps");
+        };
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected number of steps, found \"-4\""
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use aoc_core::{
+    grid::Grid,
+    parse::{integer, parse_lines, parse_with},
+    Answer, ParseError, Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{map, value},
+    sequence::preceded,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+#[allow(clippy::upper_case_acronyms)]
+struct CRT {
+    screen: Grid<bool>,
+}
+
+impl CRT {
+    fn new(height: usize, width: usize) -> Self {
+        Self {
+            screen: Grid::new(width, height, false),
+        }
+    }
+
+    fn run_step(&mut self, cycle: usize, sprite: i32) {
+        let x = cycle % self.screen.width();
+        let y = cycle / self.screen.width();
+        if x as i32 >= sprite - 1 && x as i32 <= sprite + 1 {
+            self.screen[(x, y)] = true;
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input)
+    }
+}
+
+fn part_one(instructions: &[Instruction]) -> i32 {
+    let mut x = 1;
+    let mut cycle = 0;
+    let mut total = 0;
+
+    for instruction in instructions {
+        match *instruction {
+            Instruction::Noop => {
+                cycle += 1;
+                let after_checkpoint = (cycle - 20) % 40;
+                if after_checkpoint == 0 {
+                    total += cycle * x;
+                }
+            }
+            Instruction::Addx(value) => {
+                cycle += 2;
+                let after_checkpoint = (cycle - 20) % 40;
+                if after_checkpoint == 0 || after_checkpoint == 1 {
+                    total += (cycle - after_checkpoint) * x;
+                }
+                x += value;
+            }
+        }
+    }
+
+    total
+}
+
+fn part_two(instructions: &[Instruction]) -> Answer {
+    Answer::screen(&draw_screen(instructions).screen)
+}
+
+fn draw_screen(instructions: &[Instruction]) -> CRT {
+    let mut sprite = 1;
+    let mut cycle = 0;
+
+    let mut crt = CRT::new(6, 40);
+
+    for instruction in instructions {
+        match *instruction {
+            Instruction::Noop => {
+                crt.run_step(cycle, sprite);
+                cycle += 1;
+            }
+            Instruction::Addx(value) => {
+                for _i in 0..2 {
+                    crt.run_step(cycle, sprite);
+                    cycle += 1;
+                }
+                sprite += value;
+            }
+        }
+    }
+
+    crt
+}
+
+fn parse_instructions(data: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(data, parse_instruction)
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction, ParseError> {
+    parse_with(
+        line,
+        alt((
+            value(Instruction::Noop, tag("noop")),
+            map(preceded(tag("addx "), integer), Instruction::Addx),
+        )),
+        "\"noop\" or \"addx\" followed by a number",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 10, 1);
+        let instructions = parse_instructions(&data).unwrap();
+        assert_eq!(part_one(&instructions), 13140);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 10, 1);
+        let instructions = parse_instructions(&data).unwrap();
+        let expected = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+        let Answer::Screen { text, grid } = part_two(&instructions) else {
+            panic!("Part two is not drawn on the screen");
+        };
+        assert_eq!(text, None);
+        assert_eq!(grid, expected);
+    }
+
+    #[test]
+    fn test_parse_instruction() {
+        assert_eq!(parse_instruction("addx -15"), Ok(Instruction::Addx(-15)));
+        assert_eq!(parse_instruction("noop"), Ok(Instruction::Noop));
+
+        let err = parse_instructions("noop\naddx 3\naddx three").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 6, "three"));
+    }
+}
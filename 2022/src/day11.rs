@@ -0,0 +1,298 @@
+use aoc_core::{
+    math::lcm,
+    parse::{blocks, comma_list, integer, key_value, parse_blocks, parse_with},
+    Answer, ParseError, Solution,
+};
+use std::{collections::VecDeque, rc::Rc};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, one_of, space0},
+    combinator::{map, value},
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: VecDeque<usize>,
+    operation: Rc<dyn Fn(usize) -> usize>,
+    test_divisible: usize,
+    if_true: usize,
+    if_false: usize,
+}
+
+impl TryFrom<&str> for Monkey {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut lines = value.lines().skip(1);
+
+        let items_line = lines
+            .next()
+            .ok_or_else(|| ParseError::missing(value, "starting items"))?;
+        let (_key, items) = parse_with(
+            items_line,
+            key_value(preceded(space0, tag("Starting items")), comma_list(integer)),
+            "starting items",
+        )
+        .map_err(|err| err.relative_to(value, items_line))?;
+
+        let operation_line = lines
+            .next()
+            .ok_or_else(|| ParseError::missing(value, "operation"))?;
+        let (_lhs, rhs) = operation_line.split_once(" = ").ok_or_else(|| {
+            ParseError::missing(operation_line, "\"new = \" followed by an operation")
+                .relative_to(value, operation_line)
+        })?;
+
+        let (rest, operation) = parse_operation(rhs)
+            .map_err(|_| ParseError::new(value, rhs, "operation such as \"old * 19\""))?;
+        if !rest.is_empty() {
+            return Err(ParseError::new(value, rest, "end of line"));
+        }
+
+        let test_line = lines.next();
+        let test_divisible = parse_last_number(value, test_line, "divisor of test")?;
+        if test_divisible == 0 {
+            let line = test_line.unwrap_or(value);
+            let number = line.split_whitespace().last().unwrap_or(line);
+            return Err(ParseError::new(value, number, "non-zero divisor of test"));
+        }
+        let if_true = parse_last_number(value, lines.next(), "monkey to throw to if true")?;
+        let if_false = parse_last_number(value, lines.next(), "monkey to throw to if false")?;
+
+        Ok(Monkey {
+            items: VecDeque::from(items),
+            operation: Rc::from(operation),
+            test_divisible,
+            if_true,
+            if_false,
+        })
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_monkeys(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(monkeys: &[Monkey]) -> usize {
+    let mut monkeys = monkeys.to_vec();
+
+    let mut inspection_count = vec![0; monkeys.len()];
+    let mut new_items = vec![Vec::new(); monkeys.len()];
+
+    for _round in 0..20 {
+        for (index, monkey) in monkeys.iter_mut().enumerate() {
+            monkey.items.extend(new_items[index].drain(..));
+
+            while let Some(item) = monkey.items.pop_front() {
+                inspection_count[index] += 1;
+                let new_item = (*monkey.operation)(item) / 3;
+                if new_item % monkey.test_divisible == 0 {
+                    new_items[monkey.if_true].push(new_item);
+                } else {
+                    new_items[monkey.if_false].push(new_item);
+                }
+            }
+        }
+    }
+
+    inspection_count.sort();
+    inspection_count.into_iter().rev().take(2).product()
+}
+
+fn part_two(monkeys: &[Monkey]) -> usize {
+    let mut monkeys = monkeys.to_vec();
+
+    let mut inspection_count = vec![0; monkeys.len()];
+    let mut new_items = vec![Vec::new(); monkeys.len()];
+
+    // Worry levels can be kept modulo any multiple of the divisors without changing the tests
+    let modulus = lcm(monkeys.iter().map(|monkey| monkey.test_divisible))
+        .expect("Parsing rejects input without monkeys");
+
+    for _round in 0..10_000 {
+        for (index, monkey) in monkeys.iter_mut().enumerate() {
+            monkey.items.extend(new_items[index].drain(..));
+
+            while let Some(item) = monkey.items.pop_front() {
+                inspection_count[index] += 1;
+                let new_item = (*monkey.operation)(item) % modulus;
+                if new_item % monkey.test_divisible == 0 {
+                    new_items[monkey.if_true].push(new_item);
+                } else {
+                    new_items[monkey.if_false].push(new_item);
+                }
+            }
+        }
+    }
+
+    inspection_count.sort();
+    inspection_count.into_iter().rev().take(2).product()
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let num_monkeys = blocks(input).count();
+    if num_monkeys == 0 {
+        return Err(ParseError::missing(input, "monkey"));
+    }
+
+    parse_blocks(input, |block| {
+        let monkey = Monkey::try_from(block)?;
+
+        // The monkeys to throw to are on the last two lines of the block
+        let target_lines = block.lines().skip(4);
+        for (target, line) in [monkey.if_true, monkey.if_false]
+            .into_iter()
+            .zip(target_lines)
+        {
+            if target >= num_monkeys {
+                let number = line.split_whitespace().last().unwrap_or(line);
+                return Err(ParseError::new(
+                    block,
+                    number,
+                    format!("monkey below {num_monkeys}"),
+                ));
+            }
+        }
+
+        Ok(monkey)
+    })
+}
+
+fn parse_last_number(
+    segment: &str,
+    line: Option<&str>,
+    expected: &str,
+) -> Result<usize, ParseError> {
+    let line = line.ok_or_else(|| ParseError::missing(segment, expected))?;
+    let number = line.split_whitespace().last().unwrap_or(line);
+    number
+        .parse::<usize>()
+        .map_err(|_| ParseError::new(segment, number, expected))
+}
+
+fn parse_operation(input: &str) -> IResult<&str, Box<dyn Fn(usize) -> usize>> {
+    // `None` stands for the old value itself
+    let operand = alt((value(None, tag("old")), map(integer::<usize>, Some)));
+
+    let mut parser = map(
+        tuple((tag("old "), one_of("+-*"), preceded(char(' '), operand))),
+        |(_old, operator, rhs)| -> Box<dyn Fn(usize) -> usize> {
+            match (operator, rhs) {
+                ('+', None) => Box::new(|val: usize| 2 * val),
+                ('+', Some(number)) => Box::new(move |val: usize| val + number),
+                ('-', None) => Box::new(|_val: usize| 0),
+                ('-', Some(number)) => Box::new(move |val: usize| val - number),
+                (_, None) => Box::new(|val: usize| val * val),
+                (_, Some(number)) => Box::new(move |val: usize| val * number),
+            }
+        },
+    );
+
+    parser(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_operation() {
+        let operation = |x: usize| x + 3;
+        let parsed_operation = parse_operation("old + 3").unwrap().1;
+
+        assert_eq!(parsed_operation(2), operation(2));
+
+        let operation = |x: usize| x * x;
+        let parsed_operation = parse_operation("old * old").unwrap().1;
+
+        assert_eq!(parsed_operation(5), operation(5));
+    }
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 11, 1);
+        let monkeys = parse_monkeys(&data).unwrap();
+        assert_eq!(part_one(&monkeys), 10605);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 11, 1);
+        let monkeys = parse_monkeys(&data).unwrap();
+        assert_eq!(part_two(&monkeys), 2713310158);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let data = read_example(2022, 11, 1).replace("old * 19", "old / 19");
+        let Err(err) = parse_monkeys(&data) else {
+            panic!("Parsed invalid operation");
+        };
+        assert_eq!(
+            (err.line, err.column, err.text.as_str()),
+            (3, 20, "old / 19")
+        );
+
+        let data =
+            read_example(2022, 11, 1).replace("If false: throw to monkey 1", "If false: throw");
+        let Err(err) = parse_monkeys(&data) else {
+            panic!("Parsed missing target monkey");
+        };
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (27, "monkey to throw to if false")
+        );
+    }
+
+    #[test]
+    fn test_parse_items() {
+        let data = read_example(2022, 11, 1).replace("79, 98", "79, -98");
+        let Err(err) = parse_monkeys(&data) else {
+            panic!("Parsed negative worry level");
+        };
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 23, "-98"));
+    }
+
+    #[test]
+    fn test_parse_targets() {
+        let data = read_example(2022, 11, 1).replace("throw to monkey 3", "throw to monkey 4");
+        let Err(err) = parse_monkeys(&data) else {
+            panic!("Parsed throw to missing monkey");
+        };
+        assert_eq!((err.line, err.column, err.text.as_str()), (6, 31, "4"));
+
+        let data = read_example(2022, 11, 1).replace("divisible by 23", "divisible by 0");
+        let Err(err) = parse_monkeys(&data) else {
+            panic!("Parsed zero divisor");
+        };
+        assert_eq!((err.line, err.text.as_str()), (4, "0"));
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let Err(err) = parse_monkeys("\n") else {
+            panic!("Parsed input without monkeys");
+        };
+        assert_eq!(err.expected, "monkey");
+    }
+}
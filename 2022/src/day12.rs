@@ -0,0 +1,124 @@
+use aoc_core::{
+    grid::{Coord, Edges, Grid},
+    search::bfs,
+    Answer, ParseError, Solution,
+};
+
+pub struct Hill {
+    heights: Grid<u32>,
+    start: Coord,
+    end: Coord,
+}
+
+impl TryFrom<&str> for Hill {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let map = Grid::parse(value, "height from a to z", |character| {
+            matches!(character, 'a'..='z' | 'S' | 'E').then_some(character)
+        })?;
+
+        let start = map
+            .position(|&character| character == 'S')
+            .ok_or_else(|| ParseError::missing(value, "start position S"))?;
+        let end = map
+            .position(|&character| character == 'E')
+            .ok_or_else(|| ParseError::missing(value, "end position E"))?;
+
+        let heights = map.map(|&character| match character {
+            'S' => 0,
+            'E' => 25,
+            _ => character as u32 - 'a' as u32,
+        });
+
+        Ok(Hill {
+            heights,
+            start,
+            end,
+        })
+    }
+}
+
+impl Hill {
+    fn get_valid_neighbours(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        let height = self.heights[coord];
+        self.heights
+            .neighbours(coord, Edges::Bounded)
+            .filter(move |&neighbour| self.heights[neighbour] <= height + 1)
+    }
+
+    fn get_lowest_points(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.heights
+            .iter()
+            .filter_map(|(coord, height)| (*height == 0).then_some(coord))
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Hill;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Hill::try_from(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(hill: &Hill) -> usize {
+    find_shortest_path(hill, [hill.start]).expect("Could not find shortest path")
+}
+
+fn part_two(hill: &Hill) -> usize {
+    find_shortest_path(hill, hill.get_lowest_points()).expect("Could not find shortest path")
+}
+
+fn find_shortest_path(hill: &Hill, starts: impl IntoIterator<Item = Coord>) -> Option<usize> {
+    bfs(
+        starts,
+        |&coord| hill.get_valid_neighbours(coord),
+        |&coord| coord == hill.end,
+    )
+    .goal_distance()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 12, 1);
+        let hill = Hill::try_from(data.as_str()).unwrap();
+        assert_eq!(part_one(&hill), 31);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 12, 1);
+        let hill = Hill::try_from(data.as_str()).unwrap();
+        assert_eq!(part_two(&hill), 29);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let Err(err) = Hill::try_from("Sabqponm\nabcryxxl\naccsz9xk") else {
+            panic!("Parsed invalid height");
+        };
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 6, "9"));
+
+        let Err(err) = Hill::try_from("Sabq\nabcr") else {
+            panic!("Parsed hill without end");
+        };
+        assert_eq!(err.expected, "end position E");
+    }
+}
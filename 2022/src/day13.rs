@@ -0,0 +1,144 @@
+use aoc_core::{
+    parse::{comma_list, parse_blocks, parse_with},
+    Answer, ParseError, Solution,
+};
+use nom::{
+    branch::alt,
+    character::complete,
+    combinator::{map, success},
+    sequence::delimited,
+    IResult,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item {
+    List(Vec<Item>),
+    Number(u32),
+}
+
+impl PartialOrd for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Item::Number(x), Item::Number(y)) => x.cmp(y),
+            (Item::List(x), Item::List(y)) => x.cmp(y),
+            (Item::List(_), Item::Number(_)) => self.cmp(&Item::List(vec![other.clone()])),
+            (Item::Number(_), Item::List(_)) => Item::List(vec![self.clone()]).cmp(other),
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<(Item, Item)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_pairs(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(pairs: &[(Item, Item)]) -> usize {
+    pairs
+        .iter()
+        .enumerate()
+        .filter(|(_index, (left, right))| left < right)
+        .map(|(index, _pair)| index + 1)
+        .sum()
+}
+
+fn part_two(pairs: &[(Item, Item)]) -> usize {
+    let divider1 = Item::List(vec![Item::List(vec![Item::Number(2)])]);
+    let divider2 = Item::List(vec![Item::List(vec![Item::Number(6)])]);
+
+    // Position of each divider once sorted is one more than the number of packets before it
+    let packets = pairs.iter().flat_map(|(left, right)| [left, right]);
+    let first_position = packets.clone().filter(|&packet| *packet < divider1).count() + 1;
+    let second_position = packets.filter(|&packet| *packet < divider2).count() + 2;
+
+    first_position * second_position
+}
+
+fn parse_pairs(data: &str) -> Result<Vec<(Item, Item)>, ParseError> {
+    parse_blocks(data, |block| {
+        let mut lines = block
+            .lines()
+            .map(|line| parse_with(line, parse_item, "packet"));
+        let left = lines
+            .next()
+            .ok_or_else(|| ParseError::missing(block, "packet"))??;
+        let right = lines
+            .next()
+            .ok_or_else(|| ParseError::missing(block, "second packet"))??;
+        match lines.next() {
+            Some(_) => Err(ParseError::new(
+                block,
+                block.lines().nth(2).unwrap_or(block),
+                "empty line after a pair of packets",
+            )),
+            None => Ok((left, right)),
+        }
+    })
+}
+
+fn parse_item(input: &str) -> IResult<&str, Item> {
+    alt((parse_number, parse_list))(input)
+}
+
+fn parse_number(input: &str) -> IResult<&str, Item> {
+    map(complete::u32, Item::Number)(input)
+}
+
+fn parse_list(input: &str) -> IResult<&str, Item> {
+    map(
+        delimited(
+            complete::char('['),
+            alt((comma_list(parse_item), success(Vec::new()))),
+            complete::char(']'),
+        ),
+        Item::List,
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 13, 1);
+        let pairs = parse_pairs(&data).unwrap();
+        assert_eq!(part_one(&pairs), 13);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 13, 1);
+        let pairs = parse_pairs(&data).unwrap();
+        assert_eq!(part_two(&pairs), 140);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_pairs("[1,[2]]\n[3]\n\n[1,x]\n[]").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (4, 4, "x]"));
+
+        let err = parse_pairs("[1]\n[2]\n\n[3]").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (4, "second packet"));
+    }
+}
@@ -1,31 +1,42 @@
-use std::{collections::HashMap, fs::read_to_string};
+use aoc_core::{
+    grid::Coord,
+    parse::{integer, parse_lines, parse_with},
+    sparse::SparseGrid,
+    Answer, ParseError, Solution,
+};
 
 use itertools::Itertools;
 use nom::{
-    bytes::complete::tag, character::complete, multi::separated_list1, sequence::separated_pair,
-    IResult,
+    bytes::complete::tag, character::complete, combinator::cut, multi::separated_list1,
+    sequence::separated_pair, IResult,
 };
 
-type Coord = (u32, u32);
-
 enum Material {
     Rock,
     Sand,
 }
 
-fn main() {
-    let data = parse_data("data/14.txt");
+pub struct Day14;
 
-    let answer = part_one(&data);
-    println!("Part 1: {answer}");
+impl Solution for Day14 {
+    type Input<'a> = Vec<Vec<Coord<u32>>>;
 
-    let answer = part_two(&data);
-    println!("Part 2: {answer}");
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_paths(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
 }
 
-fn part_one(data: &str) -> usize {
+fn part_one(paths: &[Vec<Coord<u32>>]) -> usize {
     let start_coord = (500, 0);
-    let (mut occupied, lowest_rock) = get_occupied(data);
+    let (mut occupied, lowest_rock) = get_occupied(paths);
 
     let mut sand_count = 0;
     'outer: loop {
@@ -35,12 +46,12 @@ fn part_one(data: &str) -> usize {
             if sand_y >= lowest_rock {
                 break 'outer;
             }
-            if !occupied.contains_key(&(sand_x, sand_y + 1)) {
+            if !occupied.contains((sand_x, sand_y + 1)) {
                 sand_y += 1;
-            } else if !occupied.contains_key(&(sand_x - 1, sand_y + 1)) {
+            } else if !occupied.contains((sand_x - 1, sand_y + 1)) {
                 sand_x -= 1;
                 sand_y += 1;
-            } else if !occupied.contains_key(&(sand_x + 1, sand_y + 1)) {
+            } else if !occupied.contains((sand_x + 1, sand_y + 1)) {
                 sand_x += 1;
                 sand_y += 1;
             } else {
@@ -54,9 +65,9 @@ fn part_one(data: &str) -> usize {
     sand_count
 }
 
-fn part_two(data: &str) -> usize {
+fn part_two(paths: &[Vec<Coord<u32>>]) -> usize {
     let start_coord = (500, 0);
-    let (mut occupied, lowest_rock) = get_occupied(data);
+    let (mut occupied, lowest_rock) = get_occupied(paths);
 
     let mut sand_count = 0;
     'outer: loop {
@@ -71,12 +82,12 @@ fn part_two(data: &str) -> usize {
                 break;
             }
 
-            if !occupied.contains_key(&(sand_x, sand_y + 1)) {
+            if !occupied.contains((sand_x, sand_y + 1)) {
                 sand_y += 1;
-            } else if !occupied.contains_key(&(sand_x - 1, sand_y + 1)) {
+            } else if !occupied.contains((sand_x - 1, sand_y + 1)) {
                 sand_x -= 1;
                 sand_y += 1;
-            } else if !occupied.contains_key(&(sand_x + 1, sand_y + 1)) {
+            } else if !occupied.contains((sand_x + 1, sand_y + 1)) {
                 sand_x += 1;
                 sand_y += 1;
             } else {
@@ -93,27 +104,21 @@ fn part_two(data: &str) -> usize {
     sand_count
 }
 
-fn get_occupied(input: &str) -> (HashMap<Coord, Material>, u32) {
-    let mut occupied: HashMap<Coord, Material> = HashMap::new();
-
-    let paths = input
-        .lines()
-        .map(|line| {
-            let (_res, path) = parse_path(line).expect("Could not parse path from line");
-            path
-        })
-        .collect::<Vec<_>>();
-
-    let mut lowest_rock = 0;
-    for rock_coord in paths.iter().flat_map(|path| coords_from_path(path)) {
-        occupied.insert(rock_coord, Material::Rock);
-        lowest_rock = u32::max(lowest_rock, rock_coord.1);
+fn get_occupied(paths: &[Vec<Coord<u32>>]) -> (SparseGrid<Material>, isize) {
+    let mut occupied = SparseGrid::new();
+
+    for (x, y) in paths.iter().flat_map(|path| coords_from_path(path)) {
+        occupied.insert((x as isize, y as isize), Material::Rock);
     }
+    // Without any rock, the sand falls straight onto the floor
+    let lowest_rock = occupied
+        .bounds()
+        .map_or(0, |(_, (_, lowest_rock))| lowest_rock);
 
     (occupied, lowest_rock)
 }
 
-fn coords_from_path(path: &[Coord]) -> impl Iterator<Item = Coord> + '_ {
+fn coords_from_path(path: &[Coord<u32>]) -> impl Iterator<Item = Coord<u32>> + '_ {
     path.windows(2).flat_map(|coords| {
         let from = coords[0];
         let to = coords[1];
@@ -127,19 +132,23 @@ fn coords_from_path(path: &[Coord]) -> impl Iterator<Item = Coord> + '_ {
     })
 }
 
-fn parse_path(line: &str) -> IResult<&str, Vec<Coord>> {
+fn parse_paths(data: &str) -> Result<Vec<Vec<Coord<u32>>>, ParseError> {
+    parse_lines(data, |line| {
+        parse_with(line, parse_path, "path such as \"498,4 -> 498,6 -> 496,6\"")
+    })
+}
+
+fn parse_path(line: &str) -> IResult<&str, Vec<Coord<u32>>> {
+    // A point after an arrow is required, so errors point at the malformed point
     separated_list1(
         tag(" -> "),
-        separated_pair(complete::u32, complete::char(','), complete::u32),
+        cut(separated_pair(integer, complete::char(','), integer)),
     )(line)
 }
 
-fn parse_data(filename: &str) -> String {
-    read_to_string(filename).expect("Could not read datafile")
-}
-
 #[cfg(test)]
 mod tests {
+    use aoc_core::read_example;
     use std::collections::HashSet;
 
     use super::*;
@@ -155,7 +164,7 @@ mod tests {
     fn test_coords_from_path() {
         let (_res, path) =
             parse_path("498,4 -> 498,6 -> 496,6").expect("Could not parse path from line");
-        let coords = coords_from_path(&path).collect::<HashSet<Coord>>();
+        let coords = coords_from_path(&path).collect::<HashSet<Coord<u32>>>();
         assert_eq!(coords.len(), 5);
         assert!(coords.contains(&(498, 4)));
         assert!(coords.contains(&(498, 5)));
@@ -166,13 +175,23 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let data = parse_data("data/14-example.txt");
-        assert_eq!(part_one(&data), 24);
+        let data = read_example(2022, 14, 1);
+        let paths = parse_paths(&data).unwrap();
+        assert_eq!(part_one(&paths), 24);
     }
 
     #[test]
     fn test_part_two() {
-        let data = parse_data("data/14-example.txt");
-        assert_eq!(part_two(&data), 93);
+        let data = read_example(2022, 14, 1);
+        let paths = parse_paths(&data).unwrap();
+        assert_eq!(part_two(&paths), 93);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_paths("498,4 -> 498,6\n503,4 -> 502").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 13, ""));
+
+        assert_eq!(part_one(&[]), 0);
     }
 }
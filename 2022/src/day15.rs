@@ -0,0 +1,260 @@
+use aoc_core::{
+    interval::IntervalSet,
+    parse::{numbers, parse_lines},
+    vector::Vec2,
+    Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+
+/// Position of a sensor and of the closest beacon it found
+type Reading = (Vec2<i64>, Vec2<i64>);
+
+struct Sensor {
+    position: Vec2<i64>,
+    range: i64,
+}
+
+impl Sensor {
+    fn in_range(&self, coord: Vec2<i64>) -> bool {
+        self.position.manhattan_distance(coord) <= self.range
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<Reading>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_readings(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input, 2_000_000).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input, (0, 4_000_000), (0, 4_000_000)).into()
+    }
+}
+
+fn part_one(readings: &[Reading], row: i64) -> i64 {
+    let mut excluded = readings
+        .iter()
+        .filter_map(|&(sensor, beacon)| {
+            let distance_to_row = i64::abs(row - sensor.y());
+            let excluded_distance = sensor.manhattan_distance(beacon) - distance_to_row;
+
+            (excluded_distance >= 0)
+                .then_some(sensor.x() - excluded_distance..=sensor.x() + excluded_distance)
+        })
+        .collect::<IntervalSet<_>>();
+
+    // Beacons that were found are not excluded
+    for (_sensor, beacon) in readings.iter().filter(|(_, beacon)| beacon.y() == row) {
+        excluded.remove(beacon.x()..=beacon.x());
+    }
+
+    excluded.total_length()
+}
+
+fn part_two(readings: &[Reading], x_range: (i64, i64), y_range: (i64, i64)) -> Option<i64> {
+    let sensors = readings
+        .iter()
+        .map(|&(sensor, beacon)| Sensor {
+            position: sensor,
+            range: sensor.manhattan_distance(beacon),
+        })
+        .collect::<Vec<_>>();
+
+    let pois = get_all_points_of_interest(&sensors);
+
+    let valid_poi = pois
+        .iter()
+        .filter(|&poi| is_valid_coord(*poi, x_range, y_range))
+        .find(|&poi| !sensors.iter().any(|sensor| sensor.in_range(*poi)))?;
+
+    Some(valid_poi.x() * 4_000_000 + valid_poi.y())
+}
+
+fn get_points_of_interest(from: &Sensor, to: &Sensor) -> Option<Vec<Vec2<i64>>> {
+    let distance = from.position.manhattan_distance(to.position);
+    let total_range = from.range + to.range;
+
+    if distance >= total_range {
+        return None;
+    }
+
+    let distance_vec = to.position - from.position;
+
+    let offset = total_range - distance;
+
+    // Creates two points of interest, since the intersection is
+    // not on one of the points on the grid
+    if offset % 2 != 0 {
+        return None;
+    }
+
+    // Intersects at a certain point on the grid
+    let offset = offset / 2;
+
+    let abs_horizontal_distance = i64::abs(distance_vec.x());
+    let abs_vertical_distance = i64::abs(distance_vec.y());
+
+    let direction = distance_vec.signum();
+    let (horizontal_direction, vertical_direction) = (direction.x(), direction.y());
+
+    let path_len = from.range - offset;
+
+    // First, traverse horizontally (only vertically if necessary)
+    // To reach POI you need to traverse vertically (away from 'to' sensor) once
+    let horizontal_steps = i64::min(abs_horizontal_distance, path_len);
+    let vertical_steps = i64::max(path_len - horizontal_steps, 0);
+
+    let closest_x = from.position.x() + horizontal_steps * horizontal_direction;
+    let closest_y = from.position.y() + vertical_steps * vertical_direction;
+
+    let poi1 = if vertical_steps > 0 {
+        let offset_direction = if horizontal_direction == 0 {
+            -1
+        } else {
+            horizontal_direction
+        };
+        Vec2::new(closest_x + (offset + 1) * offset_direction, closest_y)
+    } else {
+        let offset_direction = if vertical_direction == 0 {
+            -1
+        } else {
+            vertical_direction
+        };
+        Vec2::new(closest_x, closest_y - (offset + 1) * offset_direction)
+    };
+
+    // Then, traverse vertically (only horizontally if necessary)
+    // Again, traverse horizontally (away from 'to' sensor) once to reach POI
+    let vertical_steps = i64::min(abs_vertical_distance, path_len);
+    let horizontal_steps = i64::max(path_len - vertical_steps, 0);
+
+    let closest_x = from.position.x() + horizontal_steps * horizontal_direction;
+    let closest_y = from.position.y() + vertical_steps * vertical_direction;
+
+    let poi2 = if horizontal_steps > 0 {
+        let offset_direction = if vertical_direction == 0 {
+            1
+        } else {
+            vertical_direction
+        };
+        Vec2::new(closest_x, closest_y + (offset + 1) * offset_direction)
+    } else {
+        let offset_direction = if horizontal_direction == 0 {
+            1
+        } else {
+            horizontal_direction
+        };
+        Vec2::new(closest_x - (offset + 1) * offset_direction, closest_y)
+    };
+
+    Some(vec![poi1, poi2])
+}
+
+fn get_all_points_of_interest(sensors: &[Sensor]) -> Vec<Vec2<i64>> {
+    // TODO: For each sensor: check whether it locks a possible unseen beacon in the corner
+    // of the field. In that case, we are interested in the point in the corner.
+
+    // For each combination of sensors: check whether they have an overlapping edge.
+    // In that case, we are interested in points next to this intersection.
+    sensors
+        .iter()
+        .tuple_combinations()
+        .filter_map(|(from, to)| get_points_of_interest(from, to))
+        .flatten()
+        .collect()
+}
+
+fn is_valid_coord(coord: Vec2<i64>, x_range: (i64, i64), y_range: (i64, i64)) -> bool {
+    (x_range.0..=x_range.1).contains(&coord.x()) && (y_range.0..=y_range.1).contains(&coord.y())
+}
+
+fn parse_readings(data: &str) -> Result<Vec<Reading>, ParseError> {
+    parse_lines(data, parse_line)
+}
+
+fn parse_line(line: &str) -> Result<Reading, ParseError> {
+    match numbers(line)?[..] {
+        [sensor_x, sensor_y, beacon_x, beacon_y] => {
+            Ok((Vec2::new(sensor_x, sensor_y), Vec2::new(beacon_x, beacon_y)))
+        }
+        _ => Err(ParseError::new(
+            line,
+            line,
+            "sensor and beacon coordinates such as \"x=2, y=18\"",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        let line = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
+        let parsed_coords = parse_line(line).unwrap();
+        assert_eq!(parsed_coords, (Vec2::new(2, 18), Vec2::new(-2, 15)));
+
+        let data = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\nSensor at x=9, y=16";
+        let err = parse_readings(data).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn test_get_poi() {
+        let sensor1 = Sensor {
+            position: Vec2::new(6, 8),
+            range: 4,
+        };
+        let sensor2 = Sensor {
+            position: Vec2::new(7, 4),
+            range: 3,
+        };
+
+        let poi = get_points_of_interest(&sensor1, &sensor2).unwrap();
+        assert_eq!(poi.len(), 2);
+        assert!(poi.contains(&Vec2::new(4, 5)));
+        assert!(poi.contains(&Vec2::new(9, 6)));
+    }
+
+    // This is one of the edgecases the function does not handle well.
+    // It can find one of the POI, but would need optimalization to find all.
+    #[test]
+    fn test_get_poi_edgecase() {
+        let sensor1 = Sensor {
+            position: Vec2::new(14, 17),
+            range: 5,
+        };
+        let sensor2 = Sensor {
+            position: Vec2::new(12, 14),
+            range: 4,
+        };
+
+        let poi = get_points_of_interest(&sensor1, &sensor2).unwrap();
+        assert!(poi.contains(&Vec2::new(9, 16)));
+    }
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 15, 1);
+        let readings = parse_readings(&data).unwrap();
+        assert_eq!(part_one(&readings, 10), 26);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 15, 1);
+        let readings = parse_readings(&data).unwrap();
+        assert_eq!(part_two(&readings, (0, 20), (0, 20)), Some(56000011));
+        assert_eq!(part_two(&[], (0, 20), (0, 20)), None);
+    }
+}
@@ -0,0 +1,397 @@
+use aoc_core::{
+    bitset::DynBitSet,
+    parse::{comma_list, integer, parse_lines, parse_with, word},
+    search::all_pairs,
+    Answer, ParseError, Solution,
+};
+use std::{cmp::Reverse, collections::HashMap};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    sequence::{preceded, tuple},
+};
+
+/// Name, flow rate and the valves that tunnels lead to
+type Valve<'a> = (&'a str, usize, Vec<&'a str>);
+
+#[derive(Clone, Debug)]
+struct State {
+    position: usize,
+    pressure: usize,
+    flow_rate: usize,
+    valves: Valves,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Valves {
+    open_valves: DynBitSet,
+}
+
+impl Valves {
+    fn get_closed_valves(&self, num_valves: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..num_valves).filter(|&valve_index| !self.valve_is_open(valve_index))
+    }
+
+    fn all_valves_open(&self, num_valves: usize) -> bool {
+        self.open_valves.len() == num_valves
+    }
+
+    fn valve_is_open(&self, valve_index: usize) -> bool {
+        self.open_valves.contains(valve_index)
+    }
+
+    fn open_valve(&mut self, valve_index: usize) {
+        self.open_valves.insert(valve_index);
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Vec<Valve<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_valves(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(valves: &[Valve]) -> usize {
+    get_best_pressures(valves, 30)
+        .into_values()
+        .max()
+        .unwrap_or(0)
+}
+
+fn part_two(valves: &[Valve]) -> usize {
+    // Highest pressures first, so the search can stop once no pair can beat the best one
+    let mut best_pressures = get_best_pressures(valves, 26)
+        .into_iter()
+        .collect::<Vec<_>>();
+    best_pressures.sort_by_key(|&(_, pressure)| Reverse(pressure));
+
+    // You and the elephant each open a different set of valves
+    let mut optimal_pressure = best_pressures.first().map_or(0, |&(_, pressure)| pressure);
+    for (index, (human_valves, human_pressure)) in best_pressures.iter().enumerate() {
+        for (elephant_valves, elephant_pressure) in &best_pressures[index + 1..] {
+            if human_pressure + elephant_pressure <= optimal_pressure {
+                break;
+            }
+            if human_valves.is_disjoint(elephant_valves) {
+                optimal_pressure = human_pressure + elephant_pressure;
+            }
+        }
+    }
+
+    optimal_pressure
+}
+
+/// Best pressure that can be released in time for every set of valves that can be opened
+fn get_best_pressures(all_valves: &[Valve], total_time: usize) -> HashMap<DynBitSet, usize> {
+    // Get all-pair shortest paths
+    let shortest_paths_all = get_shortest_paths(all_valves);
+
+    // Get original indices of flowing valves
+    let flowing_valves = all_valves
+        .iter()
+        .enumerate()
+        .filter_map(|(index, (_valve, flow_rate, _connected_valves))| {
+            (*flow_rate != 0).then_some(index)
+        })
+        .collect::<Vec<_>>();
+
+    // Get flow rate of flowing valves
+    let flow_rates = flowing_valves
+        .iter()
+        .map(|index| all_valves[*index].1)
+        .collect::<Vec<_>>();
+
+    // Reduce shortest paths matrix to flowing valves only
+    let mut distances = vec![vec![usize::MAX; flowing_valves.len()]; flowing_valves.len()];
+    for (valve_index, original_index) in flowing_valves.iter().enumerate() {
+        for (conn_valve_index, conn_original_index) in flowing_valves.iter().enumerate() {
+            distances[valve_index][conn_valve_index] =
+                shortest_paths_all[*original_index][*conn_original_index];
+        }
+    }
+
+    // Get original index of starting valve (since it is not a flowing valve)
+    let starting_index = all_valves
+        .iter()
+        .position(|(name, _flow_rate, _connected_valves)| *name == "AA")
+        .expect("Could not find starting valve");
+
+    // Get distance from starting valve to each flowing valve
+    let starting_costs = flowing_valves
+        .iter()
+        .map(|index| shortest_paths_all[starting_index][*index]);
+
+    // Create a queue with states to check for each time step
+    let mut starting_queue = vec![vec![]; total_time];
+
+    // Place initial valves into queue, skipping those that cannot be reached and opened in time
+    for (valve_index, path_len) in starting_costs.enumerate() {
+        if path_len.saturating_add(1) >= total_time {
+            continue;
+        }
+        let mut state = State {
+            position: valve_index,
+            pressure: 0,
+            flow_rate: flow_rates[valve_index],
+            valves: Valves::default(),
+        };
+        state.valves.open_valve(valve_index);
+        starting_queue[path_len + 1].push(state);
+    }
+
+    find_optimal_pressure(
+        &mut starting_queue,
+        &flow_rates,
+        &flowing_valves,
+        &distances,
+        total_time,
+    )
+}
+
+fn find_optimal_pressure(
+    queue: &mut [Vec<State>],
+    flow_rates: &[usize],
+    chosen_valves: &[usize],
+    shortest_paths: &[Vec<usize>],
+    total_time: usize,
+) -> HashMap<DynBitSet, usize> {
+    // Best pressure found so far per time, position and set of open valves
+    let mut memo = vec![vec![HashMap::new(); chosen_valves.len()]; total_time];
+    let mut best_pressures = HashMap::new();
+
+    for time in 0..total_time {
+        let time_left = total_time - time;
+        let mut current_states = vec![];
+        std::mem::swap(&mut current_states, &mut queue[time]);
+
+        for state in current_states.drain(..) {
+            // Waiting from now on while the pressure builds is always an option
+            let best = best_pressures
+                .entry(state.valves.open_valves.clone())
+                .or_insert(0);
+            *best = usize::max(*best, state.pressure + time_left * state.flow_rate);
+
+            // If all valves are open, all that's left to do is wait
+            if state.valves.all_valves_open(chosen_valves.len()) {
+                continue;
+            }
+
+            // If the valve is open, there is nothing to do here; move to a different valve
+            if state.valves.valve_is_open(state.position) {
+                for neighbour in state.valves.get_closed_valves(chosen_valves.len()) {
+                    let path_len = shortest_paths[state.position][neighbour];
+
+                    if path_len >= time_left {
+                        continue;
+                    }
+                    let new_time = time + path_len;
+                    let mut new_state = state.clone();
+                    new_state.position = neighbour;
+                    new_state.pressure += path_len * state.flow_rate;
+
+                    let best = memo[new_time][new_state.position]
+                        .entry(new_state.valves.open_valves.clone())
+                        .or_insert(0);
+                    if *best < new_state.pressure {
+                        *best = new_state.pressure;
+                        queue[new_time].push(new_state);
+                    }
+                }
+            // If the valve is closed, open it
+            // (There is no point in moving, since there would always be a shorter path to this valve)
+            } else {
+                if 1 >= time_left {
+                    continue;
+                }
+
+                let new_time = time + 1;
+                let mut new_state = state.clone();
+                new_state.valves.open_valve(state.position);
+                new_state.flow_rate += flow_rates[state.position];
+                new_state.pressure += state.flow_rate;
+
+                let best = memo[new_time][new_state.position]
+                    .entry(new_state.valves.open_valves.clone())
+                    .or_insert(0);
+                if *best < new_state.pressure {
+                    *best = new_state.pressure;
+                    queue[new_time].push(new_state);
+                }
+            }
+        }
+    }
+
+    best_pressures
+}
+
+// Get the shortest paths between all valve pairs, where every tunnel takes one minute and
+// valves that cannot be reached are infinitely far away
+fn get_shortest_paths(valves: &[(&str, usize, Vec<&str>)]) -> Vec<Vec<usize>> {
+    let tunnels = valves.iter().enumerate().flat_map(
+        |(valve_index, (_valve, _flow_rate, connected_valves))| {
+            connected_valves.iter().map(move |connected_valve| {
+                let connected_index = valves
+                    .iter()
+                    .position(|(valve, _flow_rate, _connected_valves)| valve == connected_valve)
+                    .expect("Could not find index of connected valve");
+                (valve_index, connected_index, 1)
+            })
+        },
+    );
+
+    all_pairs(valves.len(), tunnels)
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|path_len| path_len.unwrap_or(usize::MAX))
+                .collect()
+        })
+        .collect()
+}
+
+fn parse_valves(data: &str) -> Result<Vec<Valve<'_>>, ParseError> {
+    let valves = parse_lines(data, parse_valve)?;
+
+    // Tunnels can only lead to valves that exist, and the search starts at valve AA
+    for (_valve, _flow_rate, connected_valves) in &valves {
+        for &connected_valve in connected_valves {
+            if !valves.iter().any(|(valve, _, _)| *valve == connected_valve) {
+                return Err(ParseError::new(data, connected_valve, "name of a valve"));
+            }
+        }
+    }
+    if !valves.iter().any(|(valve, _, _)| *valve == "AA") {
+        return Err(ParseError::missing(data, "valve AA"));
+    }
+
+    Ok(valves)
+}
+
+fn parse_valve(line: &str) -> Result<Valve<'_>, ParseError> {
+    parse_with(
+        line,
+        tuple((
+            preceded(tag("Valve "), word),
+            preceded(tag(" has flow rate="), integer),
+            preceded(
+                alt((
+                    tag("; tunnels lead to valves "),
+                    tag("; tunnel leads to valve "),
+                )),
+                comma_list(word),
+            ),
+        )),
+        "line such as \"Valve AA has flow rate=0; tunnels lead to valves DD, II\"",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_line_single_valve() {
+        let line = "Valve HH has flow rate=22; tunnel leads to valve GG";
+        assert_eq!(parse_valve(line), Ok(("HH", 22, vec!["GG"])));
+    }
+
+    #[test]
+    fn test_parse_line_multiple_valves() {
+        let line = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB";
+        assert_eq!(parse_valve(line), Ok(("AA", 0, vec!["DD", "II", "BB"])));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let data = "Valve AA has flow rate=0; tunnels lead to valves BB\nValve BB has flow rate=x; tunnel leads to valve AA";
+        let err = parse_valves(data).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 24, "x;"));
+
+        let data = "Valve AA has flow rate=0; tunnels lead to valves BB, CC\nValve BB has flow rate=3; tunnel leads to valve AA";
+        let err = parse_valves(data).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 54, "CC"));
+
+        let err = parse_valves("Valve BB has flow rate=3; tunnel leads to valve BB").unwrap_err();
+        assert_eq!(err.expected, "valve AA");
+    }
+
+    #[test]
+    fn test_closed_valves() {
+        let mut valves = Valves::default();
+
+        let mut closed_valves = valves.get_closed_valves(5);
+        assert_eq!(closed_valves.next(), Some(0));
+        assert_eq!(closed_valves.next(), Some(1));
+        assert_eq!(closed_valves.next(), Some(2));
+        assert_eq!(closed_valves.next(), Some(3));
+        assert_eq!(closed_valves.next(), Some(4));
+        assert_eq!(closed_valves.next(), None);
+        drop(closed_valves);
+
+        valves.open_valve(3);
+        let mut closed_valves = valves.get_closed_valves(5);
+        assert_eq!(closed_valves.next(), Some(0));
+        assert_eq!(closed_valves.next(), Some(1));
+        assert_eq!(closed_valves.next(), Some(2));
+        assert_eq!(closed_valves.next(), Some(4));
+        assert_eq!(closed_valves.next(), None);
+    }
+
+    #[test]
+    fn test_all_valves_open() {
+        let mut valves = Valves::default();
+
+        valves.open_valve(0);
+        valves.open_valve(1);
+        assert!(!valves.all_valves_open(3));
+
+        valves.open_valve(2);
+        assert!(valves.all_valves_open(3));
+    }
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 16, 1);
+        let valves = parse_valves(&data).unwrap();
+        assert_eq!(part_one(&valves), 1651);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 16, 1);
+        let valves = parse_valves(&data).unwrap();
+        assert_eq!(part_two(&valves), 1707);
+    }
+
+    #[test]
+    fn test_unreachable_valves() {
+        // More flowing valves than fit in a single word, most of them cut off from AA
+        let mut data = String::from(
+            "Valve AA has flow rate=0; tunnel leads to valve BB\n\
+             Valve BB has flow rate=10; tunnel leads to valve AA\n",
+        );
+        for index in 0..70 {
+            let next = (index + 1) % 70;
+            data += &format!("Valve X{index} has flow rate=1; tunnel leads to valve X{next}\n");
+        }
+
+        let valves = parse_valves(&data).unwrap();
+        assert_eq!(part_one(&valves), 280);
+        assert_eq!(part_two(&valves), 240);
+    }
+}
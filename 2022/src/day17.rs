@@ -0,0 +1,274 @@
+use aoc_core::{
+    cycle::simulate_with_cycle_skip, grid::Coord, sparse::SparseGrid, Answer, ParseError, Solution,
+};
+use std::fmt::Display;
+
+#[derive(PartialEq, Eq, Hash)]
+struct State {
+    rock_index: usize,
+    jet_index: usize,
+    floor: Vec<isize>,
+}
+
+#[derive(Debug)]
+pub enum Direction {
+    Left,
+    Right,
+    Down,
+}
+
+impl Direction {
+    fn get_offset(&self) -> Coord<isize> {
+        match self {
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, -1),
+        }
+    }
+}
+
+impl TryFrom<&str> for Direction {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            ">" => Ok(Direction::Right),
+            "<" => Ok(Direction::Left),
+            _ => Err(ParseError::new(value, value, "< or >")),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Cell {
+    Settled,
+    Falling,
+}
+
+/// Chamber with the floor at row 0 and rows counting upwards
+struct Chamber {
+    active: Vec<Coord<isize>>,
+    filled: SparseGrid<Cell>,
+    width: isize,
+    height: isize,
+    floor: Vec<isize>,
+}
+
+impl Display for Chamber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut cells = self.filled.clone();
+        for &coord in self.active.iter() {
+            cells.insert(coord, Cell::Falling);
+        }
+
+        let rendered = cells.render(
+            (0, 0),
+            (self.width - 1, self.height + 7),
+            |cell| match cell {
+                Some(Cell::Settled) => '#',
+                Some(Cell::Falling) => '@',
+                None => '.',
+            },
+        );
+        for line in rendered.lines().rev() {
+            writeln!(f, "{line}")?;
+        }
+        write!(f, "")
+    }
+}
+
+impl Chamber {
+    fn get_rocks() -> [Vec<Coord<isize>>; 5] {
+        [
+            vec![(0, 0), (1, 0), (2, 0), (3, 0)],
+            vec![(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+            vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            vec![(0, 0), (0, 1), (0, 2), (0, 3)],
+            vec![(0, 0), (1, 0), (0, 1), (1, 1)],
+        ]
+    }
+
+    fn new(width: isize) -> Self {
+        Chamber {
+            active: Vec::new(),
+            filled: (0..width).map(|x| ((x, 0), Cell::Settled)).collect(),
+            width,
+            height: 0,
+            floor: vec![0; width as usize],
+        }
+    }
+
+    fn place_rock(&mut self, rock: Vec<Coord<isize>>) {
+        let (offset_x, offset_y) = (2, self.height + 4);
+        self.active
+            .extend(rock.iter().map(|(x, y)| (x + offset_x, y + offset_y)));
+    }
+
+    fn is_legal_move(&self, direction: &Direction) -> bool {
+        let (offset_x, offset_y) = direction.get_offset();
+        self.active.iter().all(|(x, y)| {
+            let (x, y) = (x + offset_x, y + offset_y);
+            (0..self.width).contains(&x) && !self.filled.contains((x, y))
+        })
+    }
+
+    fn make_move(&mut self, direction: &Direction) {
+        let (offset_x, offset_y) = direction.get_offset();
+        for (x, y) in self.active.iter_mut() {
+            *x += offset_x;
+            *y += offset_y;
+        }
+    }
+
+    fn solidify(&mut self) {
+        for &(x, y) in self.active.iter() {
+            self.floor[x as usize] = isize::max(self.floor[x as usize], y);
+        }
+        for coord in self.active.drain(..) {
+            self.filled.insert(coord, Cell::Settled);
+        }
+        self.height = *self.floor.iter().max().unwrap();
+    }
+
+    fn get_relative_height(&self) -> impl Iterator<Item = isize> + '_ {
+        let min_height = self.floor.iter().min().unwrap();
+        self.floor.iter().map(move |height| height - min_height)
+    }
+}
+
+/// Chamber together with the next rock and jet to use
+struct Simulation<'a> {
+    chamber: Chamber,
+    rocks: [Vec<Coord<isize>>; 5],
+    jets: &'a [Direction],
+    rock_index: usize,
+    jet_index: usize,
+}
+
+impl<'a> Simulation<'a> {
+    fn new(jets: &'a [Direction]) -> Self {
+        Simulation {
+            chamber: Chamber::new(7),
+            rocks: Chamber::get_rocks(),
+            jets,
+            rock_index: 0,
+            jet_index: 0,
+        }
+    }
+
+    fn drop_rock(&mut self) {
+        self.chamber
+            .place_rock(self.rocks[self.rock_index % self.rocks.len()].clone());
+
+        loop {
+            let jet = &self.jets[self.jet_index % self.jets.len()];
+            if self.chamber.is_legal_move(jet) {
+                self.chamber.make_move(jet);
+            }
+
+            self.jet_index += 1;
+
+            if self.chamber.is_legal_move(&Direction::Down) {
+                self.chamber.make_move(&Direction::Down);
+            } else {
+                break;
+            }
+        }
+
+        self.chamber.solidify();
+        self.rock_index += 1;
+    }
+
+    fn get_state(&self) -> State {
+        State {
+            rock_index: self.rock_index % self.rocks.len(),
+            jet_index: self.jet_index % self.jets.len(),
+            floor: self.chamber.get_relative_height().collect(),
+        }
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Vec<Direction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_jets(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(jets: &[Direction]) -> isize {
+    let num_rocks = 2022;
+
+    let mut simulation = Simulation::new(jets);
+    for _ in 0..num_rocks {
+        simulation.drop_rock();
+    }
+
+    simulation.chamber.height
+}
+
+fn part_two(jets: &[Direction]) -> usize {
+    let num_rocks = 1_000_000_000_000;
+
+    let result = simulate_with_cycle_skip(
+        Simulation::new(jets),
+        |simulation| (simulation.get_state(), simulation.chamber.height),
+        Simulation::drop_rock,
+        num_rocks,
+    );
+
+    result.value as usize
+}
+
+fn parse_jets(data: &str) -> Result<Vec<Direction>, ParseError> {
+    let pattern = data.trim_end();
+    if pattern.is_empty() {
+        return Err(ParseError::missing(data, "jet pattern"));
+    }
+
+    pattern
+        .char_indices()
+        .map(|(index, chr)| {
+            let jet = &pattern[index..index + chr.len_utf8()];
+            Direction::try_from(jet).map_err(|err| err.relative_to(data, jet))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 17, 1);
+        let jets = parse_jets(&data).unwrap();
+        assert_eq!(part_one(&jets), 3068);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 17, 1);
+        let jets = parse_jets(&data).unwrap();
+        assert_eq!(part_two(&jets), 1514285714288);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_jets(">>><<>^<<\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 7, "^"));
+        assert_eq!(parse_jets("\n").unwrap_err().expected, "jet pattern");
+    }
+}
@@ -0,0 +1,165 @@
+use aoc_core::{
+    parse::{comma_list, integer, parse_lines, parse_with},
+    vector::Vec3,
+    Answer, ParseError, Solution,
+};
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Vec<Vec3<i16>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_points(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(points: &[Vec3<i16>]) -> usize {
+    get_total_sides(points.to_vec())
+}
+
+fn part_two(points: &[Vec3<i16>]) -> usize {
+    let points: HashSet<_> = points.iter().copied().collect();
+
+    let mut min = Vec3::ZERO;
+    let mut max = Vec3::ZERO;
+
+    for dimension in 0..3 {
+        min[dimension] = points.iter().map(|point| point[dimension]).min().unwrap() - 1;
+        max[dimension] = points.iter().map(|point| point[dimension]).max().unwrap() + 1;
+    }
+
+    let mut stack = vec![min];
+
+    let mut evaluated = HashSet::new();
+    let mut sides = 0;
+
+    while let Some(point) = stack.pop() {
+        let neighbours = get_neighbours(point, (min, max));
+
+        for neighbour in neighbours {
+            if points.contains(&neighbour) {
+                sides += 1;
+                continue;
+            }
+            if !evaluated.contains(&neighbour) {
+                stack.push(neighbour);
+                evaluated.insert(neighbour);
+            }
+        }
+    }
+
+    sides
+}
+
+fn get_neighbours(
+    point: Vec3<i16>,
+    (min, max): (Vec3<i16>, Vec3<i16>),
+) -> impl Iterator<Item = Vec3<i16>> {
+    Vec3::unit_vectors()
+        .map(move |unit| point + unit)
+        .filter(move |neighbour| (0..3).all(|dim| (min[dim]..=max[dim]).contains(&neighbour[dim])))
+}
+
+fn get_total_sides(mut points: Vec<Vec3<i16>>) -> usize {
+    let mut total_sides = points.len() * 6;
+
+    let dimensions = (0..3).collect::<Vec<_>>();
+    for dimension in dimensions.iter() {
+        let fixed_dimensions: Vec<_> = dimensions
+            .iter()
+            .cloned()
+            .filter(|&dim| dim != *dimension)
+            .collect();
+
+        let mut order = fixed_dimensions.clone();
+        order.push(*dimension);
+
+        points.sort_by_key(|point| get_sort_key(point, order.clone()));
+
+        total_sides -= 2 * points
+            .iter()
+            .tuple_windows()
+            .filter(|(point1, point2)| {
+                fixed_dimensions
+                    .iter()
+                    .all(|&fixed_dim| point1[fixed_dim] == point2[fixed_dim])
+                    && point1[*dimension] + 1 == point2[*dimension]
+            })
+            .count();
+    }
+
+    total_sides
+}
+
+fn get_sort_key(point: &Vec3<i16>, order: impl IntoIterator<Item = usize>) -> u32 {
+    let mut total = 0;
+    for key in order {
+        total = (total << 8) + point[key] as u32;
+    }
+    total
+}
+
+fn parse_points(data: &str) -> Result<Vec<Vec3<i16>>, ParseError> {
+    let points = parse_lines(data, parse_point)?;
+    if points.is_empty() {
+        return Err(ParseError::missing(data, "cube"));
+    }
+
+    Ok(points)
+}
+
+fn parse_point(line: &str) -> Result<Vec3<i16>, ParseError> {
+    match parse_with(line, comma_list(integer), "coordinate")?[..] {
+        [x, y, z] => Ok(Vec3::new(x, y, z)),
+        _ => Err(ParseError::new(
+            line,
+            line,
+            "three coordinates such as \"2,2,2\"",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2022, 18, 1);
+        let points = parse_points(&data).unwrap();
+        assert_eq!(part_one(&points), 64);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2022, 18, 1);
+        let points = parse_points(&data).unwrap();
+        assert_eq!(part_two(&points), 58);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_points("1,1,1\n2,x,1").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "x,1"));
+
+        let err = parse_points("1,1,1\n2,1").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "2,1"));
+
+        let err = parse_points("").unwrap_err();
+        assert_eq!(err.expected, "cube");
+    }
+}
@@ -1,12 +1,14 @@
-use std::{
-    collections::{HashMap, VecDeque},
-    fs::read_to_string,
-};
+use std::collections::{HashMap, VecDeque};
 
+use aoc_core::{
+    parse::{integer, parse_lines, parse_with, word},
+    Answer, ParseError, Solution,
+};
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take, take_while},
-    character::{complete, is_alphabetic},
+    bytes::complete::tag,
+    character::complete::{char, one_of},
+    combinator::map,
     sequence::{delimited, separated_pair, tuple},
     IResult,
 };
@@ -14,7 +16,7 @@ use nom::{
 type Monkey<'a> = (&'a str, Operation<'a>);
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-enum Operation<'a> {
+pub enum Operation<'a> {
     Assign(i64),
     Add(&'a str, &'a str),
     Subtract(&'a str, &'a str),
@@ -33,7 +35,7 @@ impl<'a> Operation<'a> {
         }
     }
 
-    fn inverse(self, destination: &'a str) -> [(&str, Self); 2] {
+    fn inverse(self, destination: &'a str) -> [(&'a str, Self); 2] {
         match self {
             Operation::Add(lhs, rhs) => [
                 (lhs, Operation::Subtract(destination, rhs)),
@@ -56,21 +58,26 @@ impl<'a> Operation<'a> {
     }
 }
 
-fn main() {
-    let data = read_data("data/21.txt");
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = Vec<Monkey<'a>>;
 
-    let answer = part_one(&data);
-    println!("Part 1: {answer}");
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_monkeys(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
 
-    let answer = part_two(&data);
-    println!("Part 2: {answer}");
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
 }
 
-fn part_one(data: &str) -> i64 {
-    let monkeys = data
-        .lines()
-        .filter_map(|line| parse_line(line).ok())
-        .map(|(_res, monkey)| monkey);
+fn part_one(monkeys: &[Monkey]) -> i64 {
+    let monkeys = monkeys.iter().cloned();
 
     let mut known_monkeys: HashMap<&str, i64> = HashMap::new();
     let mut dependencies: HashMap<&str, Vec<Monkey>> = HashMap::new();
@@ -114,11 +121,10 @@ fn part_one(data: &str) -> i64 {
         .expect("Could not find root monkey")
 }
 
-fn part_two(data: &str) -> i64 {
-    let (root, monkeys): (Vec<_>, Vec<_>) = data
-        .lines()
-        .filter_map(|line| parse_line(line).ok())
-        .map(|(_res, monkey)| monkey)
+fn part_two(monkeys: &[Monkey]) -> i64 {
+    let (root, monkeys): (Vec<_>, Vec<_>) = monkeys
+        .iter()
+        .cloned()
         .partition(|(name, _op)| name == &"root");
 
     let mut known_monkeys: HashMap<&str, i64> = HashMap::new();
@@ -219,68 +225,66 @@ fn part_two(data: &str) -> i64 {
         .expect("Could not find humn monkey")
 }
 
-fn parse_line(line: &str) -> IResult<&str, (&str, Operation)> {
-    separated_pair(
-        take(4usize),
-        tag(": "),
-        alt((parse_assign, parse_operation)),
-    )(line)
-}
+fn parse_monkeys(data: &str) -> Result<Vec<Monkey<'_>>, ParseError> {
+    let monkeys = parse_lines(data, |line| {
+        parse_with(
+            line,
+            separated_pair(word, tag(": "), alt((parse_assign, parse_operation))),
+            "monkey such as \"root: pppw + sjmn\" or \"dbpl: 5\"",
+        )
+    })?;
+
+    for name in ["root", "humn"] {
+        if !monkeys.iter().any(|(monkey, _operation)| *monkey == name) {
+            return Err(ParseError::missing(data, format!("monkey {name}")));
+        }
+    }
 
-fn parse_assign(assignment: &str) -> IResult<&str, Operation> {
-    let (res, number) = complete::i64(assignment)?;
-    IResult::Ok((res, Operation::Assign(number)))
+    Ok(monkeys)
 }
 
-fn parse_operation(operation: &str) -> IResult<&str, Operation> {
-    let (res, (lhs, op, rhs)) = tuple((
-        take_while(|x| is_alphabetic(x as u8)),
-        parse_operator,
-        take_while(|x| is_alphabetic(x as u8)),
-    ))(operation)?;
+fn parse_assign(assignment: &str) -> IResult<&str, Operation<'_>> {
+    map(integer, Operation::Assign)(assignment)
+}
 
-    IResult::Ok((
-        res,
-        match op {
+fn parse_operation(operation: &str) -> IResult<&str, Operation<'_>> {
+    map(
+        tuple((word, delimited(char(' '), one_of("+-*/"), char(' ')), word)),
+        |(lhs, operator, rhs)| match operator {
             '+' => Operation::Add(lhs, rhs),
             '-' => Operation::Subtract(lhs, rhs),
             '*' => Operation::Multiply(lhs, rhs),
-            '/' => Operation::Divide(lhs, rhs),
-            _ => unreachable!(),
+            _ => Operation::Divide(lhs, rhs),
         },
-    ))
-}
-
-fn parse_operator(operator: &str) -> IResult<&str, char> {
-    delimited(
-        complete::char(' '),
-        alt((
-            complete::char('+'),
-            complete::char('-'),
-            complete::char('*'),
-            complete::char('/'),
-        )),
-        complete::char(' '),
-    )(operator)
-}
-
-fn read_data(filename: &str) -> String {
-    read_to_string(filename).expect("Could not read datafile")
+    )(operation)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::read_example;
+
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let data = read_data("data/21-example.txt");
-        assert_eq!(part_one(&data), 152);
+        let data = read_example(2022, 21, 1);
+        let monkeys = parse_monkeys(&data).unwrap();
+        assert_eq!(part_one(&monkeys), 152);
     }
 
     #[test]
     fn test_part_two() {
-        let data = read_data("data/21-example.txt");
-        assert_eq!(part_two(&data), 301);
+        let data = read_example(2022, 21, 1);
+        let monkeys = parse_monkeys(&data).unwrap();
+        assert_eq!(part_two(&monkeys), 301);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_monkeys("root: humn % abcd\nhumn: 5").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 12, "%"));
+
+        let err = parse_monkeys("root: 3").unwrap_err();
+        assert_eq!(err.expected, "monkey humn");
     }
 }
@@ -0,0 +1,70 @@
+use aoc_core::{
+    parse::{all_chars, parse_lines},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_calibration(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(lines: &[&str]) -> Option<usize> {
+    lines.iter().map(|line| {
+        let mut numbers = line.chars().filter_map(|char| char.to_digit(10));
+        let first = numbers.next()?;
+        Some(first * 10 + if let Some(last) = numbers.next_back() { last } else { first })
+    }).sum::<Option<u32>>().map(|sum| sum as usize)
+}
+
+fn part_two(lines: &[&str]) -> Option<usize> {
+    let text_numbers = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+    lines.iter().map(|line| {
+        let mut new_line = line.to_string();
+        for (i, text_number) in text_numbers.into_iter().enumerate() {
+            let number = (i + 1).to_string();
+            new_line = new_line.replace(text_number, &format!("{text_number}{number}{text_number}"));
+        }
+        let mut numbers = new_line.chars().filter_map(|char| char.to_digit(10));
+        let first = numbers.next()?;
+        Some(first * 10 + if let Some(last) = numbers.next_back() { last } else { first })
+    }).sum::<Option<u32>>().map(|sum| sum as usize)
+}
+
+fn parse_calibration(data: &str) -> Result<Vec<&str>, ParseError> {
+    parse_lines(data, |line| {
+        all_chars(line, |chr| chr.is_ascii_alphanumeric(), "letter or digit")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example(2023, 1, 1);
+        assert_eq!(Some(142), part_one(&parse_calibration(&data).unwrap()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example(2023, 1, 2);
+        assert_eq!(Some(281), part_two(&parse_calibration(&data).unwrap()));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod day01;
+
+use aoc_core::Day;
+
+pub const DAYS: &[Day] = &[Day::new::<day01::Day01>(1)];
@@ -0,0 +1,461 @@
+use std::fmt::Debug;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Set of small indices stored as `64 * WORDS` bits, so it can be copied and hashed cheaply.
+/// Use a larger `WORDS` or [`DynBitSet`] for more than 64 elements.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitSet<const WORDS: usize = 1> {
+    words: [u64; WORDS],
+}
+
+/// Set of indices without an upper bound, growing as elements are inserted
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DynBitSet {
+    // Never ends with a zero word, so equal sets have equal words
+    words: Vec<u64>,
+}
+
+impl<const WORDS: usize> Default for BitSet<WORDS> {
+    fn default() -> Self {
+        BitSet { words: [0; WORDS] }
+    }
+}
+
+impl<const WORDS: usize> BitSet<WORDS> {
+    pub const CAPACITY: usize = WORDS * WORD_BITS;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set containing every index below `size`
+    pub fn universe(size: usize) -> Self {
+        assert!(
+            size <= Self::CAPACITY,
+            "Universe of {size} elements does not fit in {} bits",
+            Self::CAPACITY
+        );
+        let mut set = Self::new();
+        fill(&mut set.words, size);
+        set
+    }
+
+    /// Adds `index`, returning whether it was not in the set yet
+    pub fn insert(&mut self, index: usize) -> bool {
+        assert!(
+            index < Self::CAPACITY,
+            "Index {index} does not fit in {} bits",
+            Self::CAPACITY
+        );
+        insert(&mut self.words, index)
+    }
+
+    /// Removes `index`, returning whether it was in the set
+    pub fn remove(&mut self, index: usize) -> bool {
+        index < Self::CAPACITY && remove(&mut self.words, index)
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        contains(&self.words, index)
+    }
+
+    pub fn len(&self) -> usize {
+        count(&self.words)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.zip_with(other, |left, right| left | right)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.zip_with(other, |left, right| left & right)
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.zip_with(other, |left, right| left & !right)
+    }
+
+    /// Indices below `size` that are not in the set
+    pub fn complement(&self, size: usize) -> Self {
+        Self::universe(size).difference(self)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// Indices in the set in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = usize> {
+        let words = self.words;
+        (0..WORDS).flat_map(move |index| word_indices(index, words[index]))
+    }
+
+    /// Every subset of this set, starting with the empty set and ending with the set itself
+    pub fn subsets(&self) -> impl Iterator<Item = Self> {
+        let mask = *self;
+        let mut next = Some(Self::new());
+        std::iter::from_fn(move || {
+            let subset = next?;
+            let mut following = subset;
+            next = next_subset(&mut following.words, &mask.words).then_some(following);
+            Some(subset)
+        })
+    }
+
+    /// Every subset of this set with exactly `size` elements
+    pub fn subsets_of_size(&self, size: usize) -> impl Iterator<Item = Self> {
+        combinations(self.iter().collect(), size).map(|indices| indices.into_iter().collect())
+    }
+
+    /// Same set stored in a different number of words
+    pub fn resize<const OTHER: usize>(&self) -> BitSet<OTHER> {
+        self.iter().collect()
+    }
+
+    fn zip_with(&self, other: &Self, operation: impl Fn(u64, u64) -> u64) -> Self {
+        BitSet {
+            words: std::array::from_fn(|index| operation(self.words[index], other.words[index])),
+        }
+    }
+}
+
+impl DynBitSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set containing every index below `size`
+    pub fn universe(size: usize) -> Self {
+        let mut words = vec![0; size.div_ceil(WORD_BITS)];
+        fill(&mut words, size);
+        DynBitSet { words }
+    }
+
+    /// Adds `index`, returning whether it was not in the set yet
+    pub fn insert(&mut self, index: usize) -> bool {
+        let word = index / WORD_BITS;
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        insert(&mut self.words, index)
+    }
+
+    /// Removes `index`, returning whether it was in the set
+    pub fn remove(&mut self, index: usize) -> bool {
+        let removed = index < self.words.len() * WORD_BITS && remove(&mut self.words, index);
+        self.trim();
+        removed
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        contains(&self.words, index)
+    }
+
+    pub fn len(&self) -> usize {
+        count(&self.words)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.zip_with(other, |left, right| left | right)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.zip_with(other, |left, right| left & right)
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.zip_with(other, |left, right| left & !right)
+    }
+
+    /// Indices below `size` that are not in the set
+    pub fn complement(&self, size: usize) -> Self {
+        Self::universe(size).difference(self)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// Indices in the set in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(index, &word)| word_indices(index, word))
+    }
+
+    /// Every subset of this set, starting with the empty set and ending with the set itself
+    pub fn subsets(&self) -> impl Iterator<Item = Self> {
+        let mask = self.words.clone();
+        let mut next = Some(vec![0; mask.len()]);
+        std::iter::from_fn(move || {
+            let subset = next.take()?;
+            let mut following = subset.clone();
+            next = next_subset(&mut following, &mask).then_some(following);
+
+            let mut subset = DynBitSet { words: subset };
+            subset.trim();
+            Some(subset)
+        })
+    }
+
+    /// Every subset of this set with exactly `size` elements
+    pub fn subsets_of_size(&self, size: usize) -> impl Iterator<Item = Self> {
+        combinations(self.iter().collect(), size).map(|indices| indices.into_iter().collect())
+    }
+
+    fn zip_with(&self, other: &Self, operation: impl Fn(u64, u64) -> u64) -> Self {
+        let length = self.words.len().max(other.words.len());
+        let word = |words: &[u64], index: usize| words.get(index).copied().unwrap_or(0);
+        let mut set = DynBitSet {
+            words: (0..length)
+                .map(|index| operation(word(&self.words, index), word(&other.words, index)))
+                .collect(),
+        };
+        set.trim();
+        set
+    }
+
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+}
+
+impl<const WORDS: usize> FromIterator<usize> for BitSet<WORDS> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = BitSet::new();
+        for index in iter {
+            set.insert(index);
+        }
+        set
+    }
+}
+
+impl FromIterator<usize> for DynBitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = DynBitSet::new();
+        for index in iter {
+            set.insert(index);
+        }
+        set
+    }
+}
+
+impl<const WORDS: usize> From<BitSet<WORDS>> for DynBitSet {
+    fn from(set: BitSet<WORDS>) -> Self {
+        let mut set = DynBitSet {
+            words: set.words.to_vec(),
+        };
+        set.trim();
+        set
+    }
+}
+
+impl<const WORDS: usize> Debug for BitSet<WORDS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl Debug for DynBitSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+fn fill(words: &mut [u64], size: usize) {
+    for (index, word) in words.iter_mut().enumerate() {
+        let bits = size.saturating_sub(index * WORD_BITS).min(WORD_BITS);
+        *word = if bits == WORD_BITS {
+            u64::MAX
+        } else {
+            (1 << bits) - 1
+        };
+    }
+}
+
+fn insert(words: &mut [u64], index: usize) -> bool {
+    let (word, bit) = (index / WORD_BITS, index % WORD_BITS);
+    let inserted = words[word] >> bit & 1 == 0;
+    words[word] |= 1 << bit;
+    inserted
+}
+
+fn remove(words: &mut [u64], index: usize) -> bool {
+    let (word, bit) = (index / WORD_BITS, index % WORD_BITS);
+    let removed = words[word] >> bit & 1 == 1;
+    words[word] &= !(1 << bit);
+    removed
+}
+
+fn contains(words: &[u64], index: usize) -> bool {
+    words
+        .get(index / WORD_BITS)
+        .is_some_and(|word| word >> (index % WORD_BITS) & 1 == 1)
+}
+
+fn count(words: &[u64]) -> usize {
+    words.iter().map(|word| word.count_ones() as usize).sum()
+}
+
+/// Indices of the bits set in the word at position `index`
+fn word_indices(index: usize, word: u64) -> impl Iterator<Item = usize> {
+    let mut remaining = word;
+    std::iter::from_fn(move || {
+        if remaining == 0 {
+            return None;
+        }
+        let bit = remaining.trailing_zeros() as usize;
+        remaining &= remaining - 1;
+        Some(index * WORD_BITS + bit)
+    })
+}
+
+/// Moves `subset` to the next subset of `mask` in counting order.
+/// Returns `false` once it wraps around to the empty set.
+fn next_subset(subset: &mut [u64], mask: &[u64]) -> bool {
+    // Adding one with every bit outside the mask set carries straight to the next bit inside it
+    let mut carry = 1;
+    for (word, &mask_word) in subset.iter_mut().zip(mask) {
+        let (sum, overflow) = (*word | !mask_word).overflowing_add(carry);
+        *word = sum & mask_word;
+        carry = overflow as u64;
+    }
+    subset.iter().any(|&word| word != 0)
+}
+
+/// Every way to choose `size` of the `elements`, in lexicographic order
+fn combinations(elements: Vec<usize>, size: usize) -> impl Iterator<Item = Vec<usize>> {
+    let mut positions = (size <= elements.len()).then(|| (0..size).collect::<Vec<_>>());
+    std::iter::from_fn(move || {
+        let current = positions.as_ref()?;
+        let combination = current.iter().map(|&position| elements[position]).collect();
+
+        // Advance the last position that can still move right, and reset the ones after it
+        let mut next = current.clone();
+        positions = (0..size)
+            .rev()
+            .find(|&index| next[index] < elements.len() - size + index)
+            .map(|index| {
+                next[index] += 1;
+                for following in index + 1..size {
+                    next[following] = next[following - 1] + 1;
+                }
+                next
+            });
+        Some(combination)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bitset() {
+        let mut set = BitSet::<1>::new();
+        assert!(set.insert(3));
+        assert!(set.insert(63));
+        assert!(!set.insert(3));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(63));
+        assert!(!set.contains(4));
+        assert!(set.remove(63));
+        assert!(!set.remove(100));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![3]);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let left = [1, 2, 70].into_iter().collect::<BitSet<2>>();
+        let right = [2, 3, 100].into_iter().collect::<BitSet<2>>();
+
+        assert_eq!(
+            left.union(&right).iter().collect::<Vec<_>>(),
+            vec![1, 2, 3, 70, 100]
+        );
+        assert_eq!(
+            left.intersection(&right).iter().collect::<Vec<_>>(),
+            vec![2]
+        );
+        assert_eq!(
+            left.difference(&right).iter().collect::<Vec<_>>(),
+            vec![1, 70]
+        );
+        assert_eq!(left.complement(4).iter().collect::<Vec<_>>(), vec![0, 3]);
+        assert_eq!(BitSet::<2>::universe(66).len(), 66);
+        assert!(!left.is_disjoint(&right));
+        assert!(left.difference(&right).is_disjoint(&right));
+        assert!(left.intersection(&right).is_subset(&left));
+    }
+
+    #[test]
+    fn test_subsets() {
+        let set = [0, 2, 5].into_iter().collect::<BitSet>();
+        let subsets = set.subsets().collect::<Vec<_>>();
+        assert_eq!(subsets.len(), 8);
+        assert_eq!(subsets.first(), Some(&BitSet::new()));
+        assert_eq!(subsets.last(), Some(&set));
+        assert!(subsets.iter().all(|subset| subset.is_subset(&set)));
+
+        // Subsets spanning several words carry over into the next word
+        let wide = [63, 64].into_iter().collect::<BitSet<2>>();
+        assert_eq!(wide.subsets().count(), 4);
+
+        let pairs = set.subsets_of_size(2).collect::<Vec<_>>();
+        assert_eq!(pairs.len(), 3);
+        assert!(pairs.iter().all(|pair| pair.len() == 2));
+        assert_eq!(set.subsets_of_size(4).count(), 0);
+        assert_eq!(
+            set.subsets_of_size(0).collect::<Vec<_>>(),
+            vec![BitSet::new()]
+        );
+    }
+
+    #[test]
+    fn test_dyn_bitset() {
+        let mut set = DynBitSet::new();
+        set.insert(200);
+        set.insert(5);
+        assert_eq!(set.len(), 2);
+        set.remove(200);
+        assert_eq!(set, [5].into_iter().collect());
+        assert_eq!(set, DynBitSet::from([5].into_iter().collect::<BitSet<3>>()));
+
+        let universe = DynBitSet::universe(130);
+        assert_eq!(
+            universe.complement(131).iter().collect::<Vec<_>>(),
+            vec![130]
+        );
+        assert_eq!(universe.difference(&universe), DynBitSet::new());
+        assert_eq!(set.subsets().count(), 2);
+        assert_eq!(universe.subsets_of_size(129).count(), 130);
+    }
+
+    #[test]
+    fn test_resize() {
+        let set = [1, 40].into_iter().collect::<BitSet>();
+        let wide: BitSet<4> = set.resize();
+        assert_eq!(wide.iter().collect::<Vec<_>>(), vec![1, 40]);
+        assert_eq!(format!("{wide:?}"), "{1, 40}");
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, Mul, Sub},
+};
+
+/// Repetition in a simulation: the state after `start` steps comes back every `length` steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Step within the first cycle that has the same state as `step`
+    pub fn equivalent_step(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+}
+
+/// Outcome of [`simulate_with_cycle_skip`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleSkip<V> {
+    /// Value after the target number of steps
+    pub value: V,
+    /// `None` if the target was reached before any state repeated
+    pub cycle: Option<Cycle>,
+}
+
+/// Runs `step_fn` on `state` until `target_steps` steps are done or a state repeats.
+///
+/// `state_fn` returns a key that identifies the state and the value that is tracked. Once a key
+/// is seen again, the value is assumed to grow by the same amount every cycle, and the value
+/// after `target_steps` steps is extrapolated from the values in the first cycle.
+pub fn simulate_with_cycle_skip<S, K, V>(
+    mut state: S,
+    mut state_fn: impl FnMut(&S) -> (K, V),
+    mut step_fn: impl FnMut(&mut S),
+    target_steps: usize,
+) -> CycleSkip<V>
+where
+    K: Hash + Eq,
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<Output = V> + TryFrom<usize>,
+    <V as TryFrom<usize>>::Error: Debug,
+{
+    let mut seen = HashMap::new();
+    let mut values = Vec::new();
+
+    for step in 0..target_steps {
+        let (key, value) = state_fn(&state);
+        values.push(value);
+
+        if let Some(start) = seen.insert(key, step) {
+            let cycle = Cycle {
+                start,
+                length: step - start,
+            };
+            let cycles = V::try_from((target_steps - start) / cycle.length)
+                .expect("Number of cycles does not fit in value");
+            let growth = values[step] - values[start];
+            let value = values[cycle.equivalent_step(target_steps)] + growth * cycles;
+
+            return CycleSkip {
+                value,
+                cycle: Some(cycle),
+            };
+        }
+
+        step_fn(&mut state);
+    }
+
+    CycleSkip {
+        value: state_fn(&state).1,
+        cycle: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_skip() {
+        // Position on a track of 7 cells that starts 3 cells before the loop,
+        // with the value counting every lap around the loop
+        let step = |(position, laps): &mut (usize, i64)| {
+            *position += 1;
+            if *position == 10 {
+                *position = 3;
+                *laps += 1;
+            }
+        };
+        let state_fn = |&(position, laps): &(usize, i64)| (position, laps * 10 + position as i64);
+
+        let result = simulate_with_cycle_skip((0, 0), state_fn, step, 1_000_000_000);
+        assert_eq!(
+            result.cycle,
+            Some(Cycle {
+                start: 3,
+                length: 7
+            })
+        );
+
+        let mut state = (0, 0);
+        for _ in 0..1_000 {
+            step(&mut state);
+        }
+        let result = simulate_with_cycle_skip((0, 0), state_fn, step, 1_000);
+        assert_eq!(result.value, state_fn(&state).1);
+    }
+
+    #[test]
+    fn test_without_cycle() {
+        let result = simulate_with_cycle_skip(0, |&n| (n, n), |n| *n += 2, 5);
+        assert_eq!(result.value, 10);
+        assert_eq!(result.cycle, None);
+    }
+
+    #[test]
+    fn test_equivalent_step() {
+        let cycle = Cycle {
+            start: 4,
+            length: 3,
+        };
+        assert_eq!(cycle.equivalent_step(2), 2);
+        assert_eq!(cycle.equivalent_step(4), 4);
+        assert_eq!(cycle.equivalent_step(8), 5);
+    }
+}
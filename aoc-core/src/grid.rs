@@ -0,0 +1,313 @@
+use std::{
+    iter::successors,
+    ops::{Index, IndexMut},
+};
+
+use crate::ParseError;
+
+pub type Coord<T = usize> = (T, T);
+
+pub fn to_index((x, y): Coord, width: usize) -> usize {
+    y * width + x
+}
+
+pub fn to_coord(index: usize, width: usize) -> Coord {
+    (index % width, index / width)
+}
+
+/// Offsets of the horizontal and vertical neighbours, in the order up, down, left, right
+pub const ORTHOGONAL: [Coord<isize>; 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+/// Offsets of all neighbours including diagonals, in row-major order
+pub const ALL_DIRECTIONS: [Coord<isize>; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// How positions beyond the edges of a grid are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Edges {
+    /// Positions outside the grid do not exist
+    #[default]
+    Bounded,
+    /// Leaving the grid on one side enters it again on the opposite side
+    Wrapping,
+}
+
+/// Rectangular grid stored row by row, indexed by `(x, y)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from cells in row-major order
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fill rows of width {width}",
+            cells.len()
+        );
+        let height = cells.len() / width;
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Parses a character map, where every row has to be equally wide and every
+    /// character has to be accepted by `parse_cell`
+    pub fn parse(
+        data: &str,
+        expected: &str,
+        mut parse_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+
+        for line in data.trim_end().lines() {
+            let row_width = *width.get_or_insert(line.chars().count());
+            if line.is_empty() || line.chars().count() != row_width {
+                return Err(ParseError::new(
+                    data,
+                    line,
+                    format!("row of {row_width} cells"),
+                ));
+            }
+
+            for (column, character) in line.char_indices() {
+                let cell = parse_cell(character).ok_or_else(|| {
+                    let text = &line[column..column + character.len_utf8()];
+                    ParseError::new(data, text, expected)
+                })?;
+                cells.push(cell);
+            }
+        }
+
+        match width {
+            Some(width) => Ok(Grid::from_cells(width, cells)),
+            None => Err(ParseError::missing(data, "row of cells")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Coord) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[to_index((x, y), self.width)])
+    }
+
+    pub fn get_mut(&mut self, (x, y): Coord) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[to_index((x, y), self.width)])
+    }
+
+    /// Position reached by moving `offset` away from `coord`, if it lies on the grid
+    pub fn offset(&self, (x, y): Coord, (dx, dy): Coord<isize>, edges: Edges) -> Option<Coord> {
+        let (x, y) = (x as isize + dx, y as isize + dy);
+        let (width, height) = (self.width as isize, self.height as isize);
+        match edges {
+            Edges::Bounded => ((0..width).contains(&x) && (0..height).contains(&y))
+                .then_some((x as usize, y as usize)),
+            Edges::Wrapping => Some((x.rem_euclid(width) as usize, y.rem_euclid(height) as usize)),
+        }
+    }
+
+    /// Horizontal and vertical neighbours of `coord`, in the order of [`ORTHOGONAL`]
+    pub fn neighbours(&self, coord: Coord, edges: Edges) -> impl Iterator<Item = Coord> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |offset| self.offset(coord, offset, edges))
+    }
+
+    /// All neighbours of `coord` including diagonals, in row-major order
+    pub fn all_neighbours(&self, coord: Coord, edges: Edges) -> impl Iterator<Item = Coord> + '_ {
+        ALL_DIRECTIONS
+            .into_iter()
+            .filter_map(move |offset| self.offset(coord, offset, edges))
+    }
+
+    /// Positions seen when looking from `coord` in `direction`, excluding `coord` itself.
+    /// Stops at the edge of the grid, or when wrapping brings it back to `coord`.
+    pub fn ray(
+        &self,
+        coord: Coord,
+        direction: Coord<isize>,
+        edges: Edges,
+    ) -> impl Iterator<Item = Coord> + '_ {
+        successors(self.offset(coord, direction, edges), move |&next| {
+            self.offset(next, direction, edges)
+        })
+        .take_while(move |&next| next != coord)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Cells along the diagonal through `coord`, from the top left to the bottom right
+    pub fn diagonal(&self, coord: Coord) -> impl Iterator<Item = &T> {
+        let steps_back = coord.0.min(coord.1);
+        let start = (coord.0 - steps_back, coord.1 - steps_back);
+        successors(Some(start), |&(x, y)| Some((x + 1, y + 1))).map_while(|coord| self.get(coord))
+    }
+
+    /// Cells along the anti-diagonal through `coord`, from the top right to the bottom left
+    pub fn anti_diagonal(&self, coord: Coord) -> impl Iterator<Item = &T> {
+        let steps_back = (self.width - 1 - coord.0).min(coord.1);
+        let start = (coord.0 + steps_back, coord.1 - steps_back);
+        successors(Some(start), |&(x, y)| Some((x.checked_sub(1)?, y + 1)))
+            .map_while(|coord| self.get(coord))
+    }
+
+    /// Cells with their positions, in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| (to_coord(index, width), cell))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Position of the first cell, in row-major order, that matches `predicate`
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Coord> {
+        let index = self.cells.iter().position(predicate)?;
+        Some(to_coord(index, self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get(coord)
+            .unwrap_or_else(|| panic!("{coord:?} lies outside of the {width}x{height} grid"))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{coord:?} lies outside of the {width}x{height} grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_grid() -> Grid<u32> {
+        Grid::parse("123\n456\n789\n", "digit", |character| {
+            character.to_digit(10)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_index_roundtrip() {
+        assert_eq!(to_index((3, 2), 5), 13);
+        assert_eq!(to_coord(13, 5), (3, 2));
+    }
+
+    #[test]
+    fn test_parse_grid() {
+        let grid = example_grid();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 1)), None);
+
+        let err = Grid::parse("123\n4x6", "digit", |character| character.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+        let err = Grid::parse("123\n45", "digit", |character| character.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "row of 3 cells");
+    }
+
+    #[test]
+    fn test_grid_neighbours() {
+        let grid = example_grid();
+        let neighbours = grid.neighbours((0, 0), Edges::Bounded).collect::<Vec<_>>();
+        assert_eq!(neighbours, vec![(0, 1), (1, 0)]);
+
+        let neighbours = grid.neighbours((0, 0), Edges::Wrapping).collect::<Vec<_>>();
+        assert_eq!(neighbours, vec![(0, 2), (0, 1), (2, 0), (1, 0)]);
+
+        assert_eq!(grid.all_neighbours((1, 1), Edges::Bounded).count(), 8);
+        assert_eq!(grid.all_neighbours((2, 2), Edges::Bounded).count(), 3);
+    }
+
+    #[test]
+    fn test_grid_views() {
+        let grid = example_grid();
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6, 9]);
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(
+            grid.diagonal((2, 1)).copied().collect::<Vec<_>>(),
+            vec![2, 6]
+        );
+        assert_eq!(
+            grid.anti_diagonal((1, 1)).copied().collect::<Vec<_>>(),
+            vec![3, 5, 7]
+        );
+        assert_eq!(grid.position(|&cell| cell > 4), Some((1, 1)));
+        assert!(grid.map(|cell| cell % 2 == 0)[(1, 0)]);
+    }
+
+    #[test]
+    fn test_grid_ray() {
+        let grid = example_grid();
+        let ray = grid.ray((1, 1), (1, 1), Edges::Bounded).collect::<Vec<_>>();
+        assert_eq!(ray, vec![(2, 2)]);
+
+        let ray = grid
+            .ray((1, 0), (0, 1), Edges::Wrapping)
+            .collect::<Vec<_>>();
+        assert_eq!(ray, vec![(1, 1), (1, 2)]);
+    }
+}
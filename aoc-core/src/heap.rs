@@ -0,0 +1,51 @@
+use std::cmp::Ordering;
+
+/// Entry for a `BinaryHeap` that pops the lowest score first.
+/// Only the score is used for comparisons, the item can be anything.
+#[derive(Clone, Copy, Debug)]
+pub struct MinScored<K, T>(pub K, pub T);
+
+impl<K: Ord, T> Ord for MinScored<K, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl<K: Ord, T> PartialOrd for MinScored<K, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, T> Eq for MinScored<K, T> {}
+
+impl<K: Ord, T> PartialEq for MinScored<K, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BinaryHeap;
+
+    use super::*;
+
+    #[test]
+    fn test_pops_lowest_score() {
+        let mut heap = BinaryHeap::new();
+        heap.push(MinScored(3, 'c'));
+        heap.push(MinScored(1, 'a'));
+        heap.push(MinScored(2, 'b'));
+
+        assert_eq!(heap.pop().map(|entry| entry.1), Some('a'));
+        assert_eq!(heap.pop().map(|entry| entry.1), Some('b'));
+        assert_eq!(heap.pop().map(|entry| entry.1), Some('c'));
+        assert!(heap.pop().is_none());
+    }
+
+    #[test]
+    fn test_ignores_item() {
+        assert_eq!(MinScored(1, "foo"), MinScored(1, "bar"));
+    }
+}
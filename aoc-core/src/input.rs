@@ -0,0 +1,120 @@
+use std::{
+    convert::Infallible,
+    env,
+    fmt::Display,
+    fs::read_to_string,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Directory containing a folder of inputs per year, overriding `<year>/data`
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+/// Reads a committed example input from `examples/<year>/<day>-<number>.txt`
+pub fn read_example(year: u16, day: u8, number: u8) -> String {
+    let path = get_example_path(year, day, number);
+    read_to_string(&path)
+        .unwrap_or_else(|err| panic!("Could not read example {}: {err}", path.display()))
+}
+
+pub fn get_examples_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("examples")
+}
+
+pub fn get_example_path(year: u16, day: u8, number: u8) -> PathBuf {
+    get_examples_dir()
+        .join(year.to_string())
+        .join(format!("{day:02}-{number}.txt"))
+}
+
+pub fn get_data_dir(year: u16) -> PathBuf {
+    match env::var_os(DATA_DIR_VAR) {
+        Some(data_dir) => PathBuf::from(data_dir).join(year.to_string()),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(year.to_string())
+            .join("data"),
+    }
+}
+
+pub fn get_input_path(year: u16, day: u8) -> PathBuf {
+    get_data_dir(year).join(format!("{day:02}.txt"))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn for_day(year: u16, day: u8) -> Self {
+        InputSource::Path(get_input_path(year, day))
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Path(path) => read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "-" => Ok(InputSource::Stdin),
+            _ => Ok(InputSource::Path(PathBuf::from(value))),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_source_from_str() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!(
+            "other/07.txt".parse(),
+            Ok(InputSource::Path(PathBuf::from("other/07.txt")))
+        );
+    }
+
+    #[test]
+    fn test_input_path() {
+        env::remove_var(DATA_DIR_VAR);
+        assert!(get_input_path(2015, 7).ends_with("2015/data/07.txt"));
+
+        env::set_var(DATA_DIR_VAR, "/tmp/inputs");
+        assert_eq!(
+            get_input_path(2022, 12),
+            PathBuf::from("/tmp/inputs/2022/12.txt")
+        );
+        env::remove_var(DATA_DIR_VAR);
+    }
+
+    #[test]
+    fn test_example_path() {
+        assert!(get_example_path(2022, 6, 3).ends_with("examples/2022/06-3.txt"));
+    }
+}
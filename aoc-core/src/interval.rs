@@ -0,0 +1,250 @@
+use std::ops::{Add, RangeInclusive, Sub};
+
+/// Integer that can be used as the bound of an interval
+pub trait Endpoint: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! impl_endpoint {
+    ($($t:ty),*) => {
+        $(
+            impl Endpoint for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+            }
+        )*
+    };
+}
+
+impl_endpoint!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Set of integers stored as sorted, disjoint inclusive intervals.
+/// Touching intervals are merged, so every set has a single representation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value in `range`, merging it with the intervals it overlaps or touches
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let first = self
+            .intervals
+            .partition_point(|&(_, other_end)| other_end < start && other_end + T::ONE < start);
+        let last = self
+            .intervals
+            .partition_point(|&(other_start, _)| other_start <= end || other_start - T::ONE == end);
+        if first < last {
+            start = start.min(self.intervals[first].0);
+            end = end.max(self.intervals[last - 1].1);
+        }
+        self.intervals.splice(first..last, [(start, end)]);
+    }
+
+    /// Removes every value in `range`, splitting the intervals it lies within
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let first = self
+            .intervals
+            .partition_point(|&(_, other_end)| other_end < start);
+        let last = self
+            .intervals
+            .partition_point(|&(other_start, _)| other_start <= end);
+        if first >= last {
+            return;
+        }
+
+        let mut remaining = Vec::new();
+        let first_start = self.intervals[first].0;
+        if first_start < start {
+            remaining.push((first_start, start - T::ONE));
+        }
+        let last_end = self.intervals[last - 1].1;
+        if last_end > end {
+            remaining.push((end + T::ONE, last_end));
+        }
+        self.intervals.splice(first..last, remaining);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in other.iter() {
+            union.insert(range);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Vec::new();
+        let (mut left, mut right) = (self.intervals.iter(), other.intervals.iter());
+        let (mut current_left, mut current_right) = (left.next(), right.next());
+
+        while let (Some(&(left_start, left_end)), Some(&(right_start, right_end))) =
+            (current_left, current_right)
+        {
+            let (start, end) = (left_start.max(right_start), left_end.min(right_end));
+            if start <= end {
+                intersection.push((start, end));
+            }
+            if left_end < right_end {
+                current_left = left.next();
+            } else {
+                current_right = right.next();
+            }
+        }
+
+        IntervalSet {
+            intervals: intersection,
+        }
+    }
+
+    /// Values in this set that are not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in other.iter() {
+            difference.remove(range);
+        }
+        difference
+    }
+
+    /// Values within `bounds` that are not in the set
+    pub fn gaps(&self, bounds: RangeInclusive<T>) -> Self {
+        IntervalSet::from(bounds).difference(self)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.contains_range(&(value..=value))
+    }
+
+    /// Whether every value in `range` is in the set
+    pub fn contains_range(&self, range: &RangeInclusive<T>) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|&(_, end)| end < *range.start());
+        self.intervals
+            .get(index)
+            .is_some_and(|&(start, end)| start <= *range.start() && *range.end() <= end)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of values in the set
+    pub fn total_length(&self) -> T {
+        self.intervals.iter().fold(T::ZERO, |total, &(start, end)| {
+            total + (end - start) + T::ONE
+        })
+    }
+
+    /// Disjoint intervals in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.iter().map(|&(start, end)| start..=end)
+    }
+}
+
+impl<T: Endpoint> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Endpoint> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let set = [0..=3, 1..=12, 1..=3, 14..=18]
+            .into_iter()
+            .collect::<IntervalSet<i64>>();
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..=12, 14..=18]);
+        assert_eq!(set.total_length(), 18);
+
+        let mut set = set;
+        set.insert(13..=13);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..=18]);
+        set.insert(RangeInclusive::new(5, 2));
+        assert_eq!(set.iter().count(), 1);
+
+        let set = [250..=255u8, 0..=0, 254..=255]
+            .into_iter()
+            .collect::<IntervalSet<_>>();
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..=0, 250..=255]);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set = [0..=10, 20..=30].into_iter().collect::<IntervalSet<i32>>();
+        set.remove(5..=5);
+        set.remove(8..=22);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..=4, 6..=7, 23..=30]);
+        set.remove(-5..=40);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let left = [0..=5, 10..=15].into_iter().collect::<IntervalSet<usize>>();
+        let right = [3..=11, 15..=20]
+            .into_iter()
+            .collect::<IntervalSet<usize>>();
+
+        assert_eq!(left.union(&right).iter().collect::<Vec<_>>(), vec![0..=20]);
+        assert_eq!(
+            left.intersection(&right).iter().collect::<Vec<_>>(),
+            vec![3..=5, 10..=11, 15..=15]
+        );
+        assert_eq!(
+            left.difference(&right).iter().collect::<Vec<_>>(),
+            vec![0..=2, 12..=14]
+        );
+        assert_eq!(
+            left.gaps(0..=25).iter().collect::<Vec<_>>(),
+            vec![6..=9, 16..=25]
+        );
+    }
+
+    #[test]
+    fn test_contains() {
+        let set = [2..=4, 300..=400].into_iter().collect::<IntervalSet<u32>>();
+        assert!(set.contains(2));
+        assert!(!set.contains(5));
+        assert!(set.contains_range(&(310..=400)));
+        assert!(!set.contains_range(&(3..=300)));
+    }
+}
@@ -0,0 +1,17 @@
+pub mod bitset;
+pub mod cycle;
+pub mod grid;
+pub mod heap;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod ocr;
+pub mod parse;
+pub mod search;
+pub mod solution;
+pub mod sparse;
+pub mod vector;
+
+pub use input::read_example;
+pub use parse::ParseError;
+pub use solution::{Answer, Day, Solution, Timings};
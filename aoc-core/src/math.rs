@@ -0,0 +1,197 @@
+use std::ops::{Div, Mul, Rem};
+
+/// Greatest common divisor of two non-negative integers
+pub fn gcd<T>(mut a: T, mut b: T) -> T
+where
+    T: Copy + Default + PartialEq + Rem<Output = T>,
+{
+    while b != T::default() {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple of all values, or `None` if there are none
+pub fn lcm<T>(values: impl IntoIterator<Item = T>) -> Option<T>
+where
+    T: Copy + Default + PartialEq + Rem<Output = T> + Div<Output = T> + Mul<Output = T>,
+{
+    values.into_iter().reduce(|a, b| {
+        if a == T::default() || b == T::default() {
+            T::default()
+        } else {
+            a / gcd(a, b) * b
+        }
+    })
+}
+
+/// Prime factors of `n` in ascending order, each with its exponent
+pub fn prime_factors(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    let mut factor = 2;
+
+    // Dividing instead of squaring keeps the bound from overflowing for large primes
+    while factor <= n / factor {
+        let mut exponent = 0;
+        while n.is_multiple_of(factor) {
+            n /= factor;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((factor, exponent));
+        }
+        factor += if factor == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+
+    factors
+}
+
+/// All divisors of `n` in ascending order
+pub fn divisors(n: u64) -> Vec<u64> {
+    let mut divisors = vec![1];
+    for (prime, exponent) in prime_factors(n) {
+        let count = divisors.len();
+        let mut power = 1;
+        for _ in 0..exponent {
+            power *= prime;
+            for index in 0..count {
+                divisors.push(divisors[index] * power);
+            }
+        }
+    }
+
+    divisors.sort_unstable();
+    divisors
+}
+
+/// Sum of all divisors of `n`, including `n` itself
+pub fn divisor_sum(n: u64) -> u64 {
+    prime_factors(n)
+        .into_iter()
+        .map(|(prime, exponent)| (prime.pow(exponent + 1) - 1) / (prime - 1))
+        .product()
+}
+
+/// `base` to the power `exponent`, modulo `modulus`. `None` for a zero modulus.
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        return None;
+    }
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1 % modulus;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+
+    Some(result as u64)
+}
+
+/// Value `x` such that `a * x` is 1 modulo `modulus`, if `a` and `modulus` are coprime.
+/// `None` for a zero modulus.
+pub fn mod_inverse(a: u64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        return None;
+    }
+    let (gcd, x, _y) = extended_gcd(a as i128, modulus as i128);
+    (gcd == 1).then(|| x.rem_euclid(modulus as i128) as u64)
+}
+
+/// Smallest non-negative `x` with `x % modulus == remainder` for every `(remainder, modulus)`,
+/// together with the lcm of the moduli that all other solutions differ by.
+/// `None` if the congruences contradict each other or any modulus is zero.
+pub fn chinese_remainder(congruences: impl IntoIterator<Item = (u64, u64)>) -> Option<(u64, u64)> {
+    congruences.into_iter().try_fold(
+        (0, 1),
+        |(remainder, modulus), (other_remainder, other_modulus)| {
+            if other_modulus == 0 {
+                return None;
+            }
+            let (remainder, modulus) = (remainder as i128, modulus as i128);
+            let (other_remainder, other_modulus) = (other_remainder as i128, other_modulus as i128);
+
+            // Solve remainder + modulus * k = other_remainder (mod other_modulus) for k
+            let (gcd, inverse, _y) = extended_gcd(modulus, other_modulus);
+            let difference = other_remainder - remainder;
+            if difference % gcd != 0 {
+                return None;
+            }
+
+            let step = other_modulus / gcd;
+            let k = (difference / gcd % step * inverse).rem_euclid(step);
+            let combined_modulus = modulus * step;
+            let combined_remainder = (remainder + modulus * k).rem_euclid(combined_modulus);
+            Some((combined_remainder as u64, combined_modulus as u64))
+        },
+    )
+}
+
+/// `(gcd, x, y)` such that `a * x + b * y == gcd`
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (gcd, x, y) = extended_gcd(b, a % b);
+        (gcd, y, x - a / b * y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7usize), 7);
+        assert_eq!(lcm([23, 19, 13, 17]), Some(96577));
+        assert_eq!(lcm([4u8, 6, 10]), Some(60));
+        assert_eq!(lcm(Vec::<u32>::new()), None);
+    }
+
+    #[test]
+    fn test_divisors() {
+        assert_eq!(prime_factors(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(prime_factors(1), vec![]);
+        assert_eq!(divisors(12), vec![1, 2, 3, 4, 6, 12]);
+        assert_eq!(divisors(1), vec![1]);
+        assert_eq!(divisors(49), vec![1, 7, 49]);
+
+        for n in 1..200 {
+            assert_eq!(divisor_sum(n), divisors(n).into_iter().sum::<u64>());
+        }
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(2, 10, 1000), Some(24));
+        assert_eq!(mod_pow(7, 0, 1), Some(0));
+        assert_eq!(mod_pow(u64::MAX, 2, u64::MAX - 1), Some(1));
+        assert_eq!(mod_pow(2, 10, 0), None);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(10, 17), Some(12));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(1, 0), None);
+    }
+
+    #[test]
+    fn test_chinese_remainder() {
+        assert_eq!(chinese_remainder([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(chinese_remainder([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(chinese_remainder([(1, 4), (2, 6)]), None);
+        assert_eq!(chinese_remainder([]), Some((0, 1)));
+        assert_eq!(chinese_remainder([(1, 4), (0, 0)]), None);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::grid::Grid;
+
+/// Letters of the 4x6 font, drawn with `#` for lit pixels
+const SMALL_FONT: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('T', ["###.", ".#..", ".#..", ".#..", ".#..", ".#.."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Letters of the 6x10 font, drawn with `#` for lit pixels
+#[rustfmt::skip]
+const LARGE_FONT: &[(char, [&str; 10])] = &[
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+/// Reads the letters in a rendered grid, where any character other than `#` is unlit.
+/// Returns `None` if the grid is not made up of known letters.
+pub fn recognize(grid: &str) -> Option<String> {
+    let rows = grid
+        .lines()
+        .map(|line| line.chars().map(|pixel| pixel == '#').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let width = rows.iter().map(Vec::len).max()?;
+
+    let pixels = rows
+        .into_iter()
+        .flat_map(|mut row| {
+            row.resize(width, false);
+            row
+        })
+        .collect();
+    recognize_grid(&Grid::from_cells(width, pixels))
+}
+
+/// Reads the letters lit in `grid`, in either the 4x6 or the 6x10 font.
+/// Letters can be anywhere in the grid, as long as they are next to each other with
+/// at least one unlit column in between.
+/// Returns `None` if the grid is not made up of known letters.
+pub fn recognize_grid(grid: &Grid<bool>) -> Option<String> {
+    let lit_rows = (0..grid.height())
+        .filter(|&y| grid.row(y).contains(&true))
+        .collect::<Vec<_>>();
+    let (&top, &bottom) = (lit_rows.first()?, lit_rows.last()?);
+    let is_lit_column = |x: usize| (top..=bottom).any(|y| grid[(x, y)]);
+
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < grid.width() {
+        if !is_lit_column(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < grid.width() && is_lit_column(x) {
+            x += 1;
+        }
+        let glyph = (top..=bottom)
+            .map(|y| (start..x).map(|x| grid[(x, y)]).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let letter = match glyph.len() {
+            6 => find_letter(SMALL_FONT, &glyph),
+            10 => find_letter(LARGE_FONT, &glyph),
+            _ => None,
+        }?;
+        letters.push(letter);
+    }
+
+    Some(letters)
+}
+
+fn find_letter<const HEIGHT: usize>(
+    font: &[(char, [&str; HEIGHT])],
+    glyph: &[Vec<bool>],
+) -> Option<char> {
+    font.iter()
+        .find(|(_, letter)| get_pixels(letter) == glyph)
+        .map(|(character, _)| *character)
+}
+
+/// Pixels of a letter in a font, without the unlit columns on either side
+fn get_pixels(letter: &[&str]) -> Vec<Vec<bool>> {
+    let rows = letter
+        .iter()
+        .map(|row| row.chars().map(|pixel| pixel == '#').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let is_lit_column = |x: usize| rows.iter().any(|row| row[x]);
+
+    let width = rows[0].len();
+    let start = (0..width).find(|&x| is_lit_column(x)).unwrap_or(0);
+    let end = (0..width).rfind(|&x| is_lit_column(x)).map_or(0, |x| x + 1);
+    rows.iter().map(|row| row[start..end].to_vec()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recognize() {
+        let grid = "\
+####.#..#.###..
+#....#..#.#..#.
+###..####.###..
+#....#..#.#..#.
+#....#..#.#..#.
+####.#..#.###..
+";
+        assert_eq!(recognize(grid), Some("EHB".to_string()));
+        assert_eq!(recognize(&grid.replace('.', " ")), Some("EHB".to_string()));
+
+        let grid = "\
+###.#...#
+.#..#...#
+.#...#.#.
+.#....#..
+.#....#..
+.#....#..
+";
+        assert_eq!(recognize(grid), Some("TY".to_string()));
+
+        assert_eq!(recognize("##..##..\n###...##\n"), None);
+        assert_eq!(recognize(&grid.replacen('#', ".", 1)), None);
+    }
+
+    #[test]
+    fn test_recognize_large() {
+        let grid = "\
+..............................
+...#....#..######...####......
+...#....#..#.......#....#.....
+...#....#..#.......#..........
+...#....#..#.......#..........
+...######..#####...#..........
+...#....#..#.......#..........
+...#....#..#.......#..........
+...#....#..#.......#..........
+...#....#..#.......#....#.....
+...#....#..######...####......
+..............................
+";
+        assert_eq!(recognize(grid), Some("HEC".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_grid() {
+        let mut grid = Grid::new(12, 8, false);
+        for (y, row) in SMALL_FONT[8].1.iter().enumerate() {
+            for (x, pixel) in row.chars().enumerate() {
+                grid[(x + 3, y + 1)] = pixel == '#';
+            }
+        }
+        assert_eq!(recognize_grid(&grid), Some("J".to_string()));
+        assert_eq!(recognize_grid(&Grid::new(5, 6, false)), None);
+    }
+}
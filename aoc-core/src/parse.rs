@@ -0,0 +1,398 @@
+use std::{any::type_name, fmt::Display, str::FromStr};
+
+use nom::{
+    character::complete::{char, digit1, one_of, space0},
+    combinator::{cut, map_res, opt, recognize},
+    sequence::{pair, separated_pair},
+    IResult,
+};
+
+/// Error for malformed input, pointing at the offending text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Creates an error for `text`, which should be a slice of `source` so its position can be found
+    pub fn new(source: &str, text: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = match get_offset(source, text) {
+            Some(offset) => get_position(source, offset),
+            None => (1, 1),
+        };
+
+        ParseError {
+            line,
+            column,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Creates an error for input that ended before `expected` was found
+    pub fn missing(source: &str, expected: impl Into<String>) -> Self {
+        ParseError::new(source, &source[source.len()..], expected)
+    }
+
+    /// Moves an error created for `part` to its position within `source`
+    pub fn relative_to(mut self, source: &str, part: &str) -> Self {
+        if let Some(offset) = get_offset(source, part) {
+            let (line, column) = get_position(source, offset);
+            if self.line == 1 {
+                self.column += column - 1;
+            }
+            self.line += line - 1;
+        }
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        if self.text.is_empty() {
+            write!(f, "found end of input")
+        } else {
+            write!(f, "found \"{}\"", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses every line of the input, placing errors on the line they occurred
+pub fn parse_lines<'a, T>(
+    data: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    data.lines()
+        .map(|line| parse(line).map_err(|err| err.relative_to(data, line)))
+        .collect()
+}
+
+/// The only line of the input that is not blank
+pub fn single_line<'a>(data: &'a str, expected: &str) -> Result<&'a str, ParseError> {
+    let mut lines = data.lines().filter(|line| !line.trim().is_empty());
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::missing(data, expected))?;
+    match lines.next() {
+        Some(extra) => Err(ParseError::new(data, get_token(extra), "end of input")),
+        None => Ok(line),
+    }
+}
+
+/// `text` itself if it is not empty and every character is accepted by `valid`
+pub fn all_chars<'a>(
+    text: &'a str,
+    valid: impl Fn(char) -> bool,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    match text.char_indices().find(|&(_index, chr)| !valid(chr)) {
+        Some((index, chr)) => Err(ParseError::new(
+            text,
+            &text[index..index + chr.len_utf8()],
+            expected,
+        )),
+        None if text.is_empty() => Err(ParseError::missing(text, expected)),
+        None => Ok(text),
+    }
+}
+
+/// Parses every character of the input except line breaks, placing errors on the character
+/// that is not recognised
+pub fn parse_chars<T>(
+    data: &str,
+    mut parse: impl FnMut(char) -> Option<T>,
+    expected: &str,
+) -> Result<Vec<T>, ParseError> {
+    data.lines()
+        .flat_map(|line| {
+            line.char_indices()
+                .map(move |(index, chr)| (line, index, chr))
+        })
+        .map(|(line, index, chr)| {
+            parse(chr).ok_or_else(|| {
+                ParseError::new(data, &line[index..index + chr.len_utf8()], expected)
+            })
+        })
+        .collect()
+}
+
+/// Parses every blank-line-separated block of the input, placing errors in the block they occurred
+pub fn parse_blocks<'a, T>(
+    data: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    blocks(data)
+        .map(|block| parse(block).map_err(|err| err.relative_to(data, block)))
+        .collect()
+}
+
+/// Blocks of the input that are separated by blank lines
+pub fn blocks(data: &str) -> impl Iterator<Item = &str> {
+    data.split("\n\n")
+        .map(|block| block.trim_matches('\n'))
+        .filter(|block| !block.is_empty())
+}
+
+/// Runs a nom parser on all of `input`, turning a failure into an error at the text it failed on
+pub fn parse_with<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+    expected: &str,
+) -> Result<T, ParseError> {
+    match parser(input) {
+        Ok((rest, value)) if rest.trim_end().is_empty() => Ok(value),
+        Ok((rest, _value)) => Err(ParseError::new(input, get_token(rest), "end of input")),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            Err(ParseError::new(input, get_token(err.input), expected))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::missing(input, expected)),
+    }
+}
+
+/// Every integer in `line`. A `-` directly before the digits is read as a sign,
+/// unless it follows a letter or digit as in `2-4`.
+pub fn numbers<T: FromStr>(line: &str) -> Result<Vec<T>, ParseError> {
+    let bytes = line.as_bytes();
+    let mut numbers = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if !bytes[index].is_ascii_digit() {
+            index += 1;
+            continue;
+        }
+
+        let mut start = index;
+        if start > 0
+            && bytes[start - 1] == b'-'
+            && (start == 1 || !bytes[start - 2].is_ascii_alphanumeric())
+        {
+            start -= 1;
+        }
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+
+        let number = &line[start..index];
+        let value = number.parse().map_err(|_| {
+            ParseError::new(
+                line,
+                number,
+                format!("number that fits in {}", type_name::<T>()),
+            )
+        })?;
+        numbers.push(value);
+    }
+
+    Ok(numbers)
+}
+
+/// Integer of any width with an optional sign, failing if it does not fit in `T`
+pub fn integer<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// Letters, digits and underscores
+pub fn word(input: &str) -> IResult<&str, &str> {
+    nom::bytes::complete::take_while1(|chr: char| chr.is_alphanumeric() || chr == '_')(input)
+}
+
+/// `key: value`, with any number of spaces after the colon
+pub fn key_value<'a, K, V>(
+    key: impl FnMut(&'a str) -> IResult<&'a str, K>,
+    value: impl FnMut(&'a str) -> IResult<&'a str, V>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (K, V)> {
+    separated_pair(key, pair(char(':'), space0), value)
+}
+
+/// One or more items separated by commas, with any number of spaces after each comma.
+/// Every comma must be followed by an item, so errors point at the item that is malformed.
+pub fn comma_list<'a, T>(
+    mut item: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    move |input| {
+        let (mut input, first) = item(input)?;
+        let mut items = vec![first];
+        while let Ok((rest, _separator)) = separator(input) {
+            let (rest, next) = cut(&mut item)(rest)?;
+            items.push(next);
+            input = rest;
+        }
+        Ok((input, items))
+    }
+}
+
+fn separator(input: &str) -> IResult<&str, (char, &str)> {
+    pair(char(','), space0)(input)
+}
+
+/// Word at the start of `input`, or its first character if that is whitespace
+fn get_token(input: &str) -> &str {
+    let end = match input.chars().next() {
+        Some(chr) if chr.is_whitespace() => chr.len_utf8(),
+        _ => input.find(char::is_whitespace).unwrap_or(input.len()),
+    };
+    &input[..end]
+}
+
+fn get_offset(source: &str, part: &str) -> Option<usize> {
+    let start = source.as_ptr() as usize;
+    let part_start = part.as_ptr() as usize;
+    (part_start >= start && part_start + part.len() <= start + source.len())
+        .then(|| part_start - start)
+}
+
+fn get_position(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let data = "inc a\njmp +2\njie q, +4";
+        let register = &data[17..18];
+        let err = ParseError::new(data, register, "register");
+        assert_eq!((err.line, err.column), (3, 5));
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 5: expected register, found \"q\""
+        );
+    }
+
+    #[test]
+    fn test_missing() {
+        let err = ParseError::missing("jmp", "offset");
+        assert_eq!((err.line, err.column), (1, 4));
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 4: expected offset, found end of input"
+        );
+    }
+
+    #[test]
+    fn test_unrelated_text() {
+        let err = ParseError::new("abc", "xyz", "letter");
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let data = "1\n2\nthree\n4";
+        let err = parse_lines(data, |line| {
+            line.parse::<u32>()
+                .map_err(|_| ParseError::new(line, line, "number"))
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.text, "three");
+
+        assert_eq!(parse_lines("1\n2", |line| Ok(line.len())), Ok(vec![1, 1]));
+    }
+
+    #[test]
+    fn test_single_line() {
+        assert_eq!(single_line("abc\n\n", "key"), Ok("abc"));
+
+        let err = single_line("abc\ndef\n", "key").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 1, "end of input")
+        );
+        assert_eq!(single_line("\n", "key").unwrap_err().expected, "key");
+
+        let digit = |chr: char| chr.is_ascii_digit();
+        assert_eq!(all_chars("123", digit, "digit"), Ok("123"));
+        let err = all_chars("12a3", digit, "digit").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (3, "a"));
+        assert!(all_chars("", digit, "digit").is_err());
+    }
+
+    #[test]
+    fn test_parse_chars() {
+        let parse = |chr: char| chr.to_digit(2);
+        assert_eq!(parse_chars("01\n1\n", parse, "bit"), Ok(vec![0, 1, 1]));
+
+        let err = parse_chars("01\n12", parse, "bit").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "2"));
+    }
+
+    #[test]
+    fn test_parse_blocks() {
+        let data = "1\n2\n\n3\n\n\nfour\n";
+        assert_eq!(blocks(data).collect::<Vec<_>>(), vec!["1\n2", "3", "four"]);
+
+        let err = parse_blocks(data, |block| {
+            parse_lines(block, |line| {
+                line.parse::<u32>()
+                    .map_err(|_| ParseError::new(line, line, "number"))
+            })
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (7, 1, "four"));
+    }
+
+    #[test]
+    fn test_numbers() {
+        let line = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15";
+        assert_eq!(numbers::<i64>(line), Ok(vec![2, -18, -2, 15]));
+        assert_eq!(numbers::<u8>("2-4,6-80"), Ok(vec![2, 4, 6, 80]));
+        assert_eq!(numbers::<u8>("no numbers"), Ok(vec![]));
+
+        let err = numbers::<u8>("1, 22, 333").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (8, "333"));
+        assert_eq!(err.expected, "number that fits in u8");
+        assert_eq!(numbers::<u32>("a -1").unwrap_err().text, "-1");
+    }
+
+    #[test]
+    fn test_integer() {
+        assert_eq!(integer::<i8>("-128,"), Ok((",", -128)));
+        assert_eq!(integer::<u64>("+42"), Ok(("", 42)));
+        assert!(integer::<i8>("128").is_err());
+        assert!(integer::<u32>("-1").is_err());
+        assert!(integer::<i32>("x").is_err());
+    }
+
+    #[test]
+    fn test_key_value_list() {
+        let mut parser = key_value(word, comma_list(integer::<i32>));
+        assert_eq!(
+            parse_with("Items: 79, -98,3", &mut parser, "items"),
+            Ok(("Items", vec![79, -98, 3]))
+        );
+
+        let err = parse_with("Items: 79, x", &mut parser, "items").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (12, "x"));
+
+        let err = parse_with("Items: 79 x", &mut parser, "items").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (10, " "));
+        assert_eq!(err.expected, "end of input");
+
+        let err = parse_with("Items 79", &mut parser, "items").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (6, " "));
+        assert_eq!(err.expected, "items");
+
+        let err = parse_with("", word, "name").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected name, found end of input"
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::heap::MinScored;
+
+/// Distances and predecessors of every node reached by a search
+#[derive(Debug, Clone)]
+pub struct SearchResult<N, C> {
+    distances: HashMap<N, C>,
+    predecessors: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Hash + Eq + Clone, C: Copy> SearchResult<N, C> {
+    fn new() -> Self {
+        SearchResult {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// First goal that was reached, which is the closest one
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    pub fn goal_distance(&self) -> Option<C> {
+        self.goal.as_ref().and_then(|goal| self.distance(goal))
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    pub fn predecessors(&self) -> &HashMap<N, N> {
+        &self.predecessors
+    }
+
+    /// Nodes from the start the node was reached from up to and including the node itself
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(predecessor) = self.predecessors.get(path.last()?) {
+            path.push(predecessor.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+}
+
+/// Breadth-first search where every step costs one.
+/// Stops at the first node for which `is_goal` holds, or once every reachable node is visited.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N, usize>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if result.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            result.goal = Some(node);
+            break;
+        }
+
+        let distance = result.distances[&node] + 1;
+        for neighbour in neighbours(&node) {
+            if let Entry::Vacant(entry) = result.distances.entry(neighbour.clone()) {
+                entry.insert(distance);
+                result.predecessors.insert(neighbour.clone(), node.clone());
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    result
+}
+
+/// Shortest paths for non-negative costs, where `neighbours` gives every next node with the
+/// cost of the step towards it
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N, C>
+where
+    N: Hash + Eq + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// Dijkstra guided by a `heuristic` that never overestimates the cost to the closest goal
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N, C>
+where
+    N: Hash + Eq + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut result = SearchResult::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        result.distances.insert(start.clone(), C::default());
+        queue.push(MinScored(heuristic(&start), (C::default(), start)));
+    }
+
+    while let Some(MinScored(_, (cost, node))) = queue.pop() {
+        // Skip entries for nodes that were reached more cheaply after being queued
+        if result.distances.get(&node).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        if is_goal(&node) {
+            result.goal = Some(node);
+            break;
+        }
+
+        for (neighbour, step) in neighbours(&node) {
+            let new_cost = cost + step;
+            if result
+                .distances
+                .get(&neighbour)
+                .is_some_and(|&best| best <= new_cost)
+            {
+                continue;
+            }
+            result.distances.insert(neighbour.clone(), new_cost);
+            result.predecessors.insert(neighbour.clone(), node.clone());
+            queue.push(MinScored(
+                new_cost + heuristic(&neighbour),
+                (new_cost, neighbour),
+            ));
+        }
+    }
+
+    result
+}
+
+/// Floyd-Warshall shortest paths between every pair of the nodes `0..size`,
+/// given as `(from, to, cost)` edges. Unreachable pairs are `None`.
+pub fn all_pairs<C>(
+    size: usize,
+    edges: impl IntoIterator<Item = (usize, usize, C)>,
+) -> Vec<Vec<Option<C>>>
+where
+    C: Copy + Ord + Add<Output = C> + Default,
+{
+    let mut distances = vec![vec![None; size]; size];
+    for (node, row) in distances.iter_mut().enumerate() {
+        row[node] = Some(C::default());
+    }
+    for (from, to, cost) in edges {
+        let distance = &mut distances[from][to];
+        if distance.is_none_or(|current| cost < current) {
+            *distance = Some(cost);
+        }
+    }
+
+    for k in 0..size {
+        let from_k = distances[k].clone();
+        for row in distances.iter_mut() {
+            let Some(to_k) = row[k] else {
+                continue;
+            };
+            for (distance, from_k) in row.iter_mut().zip(&from_k) {
+                if let Some(from_k) = *from_k {
+                    let via_k = to_k + from_k;
+                    if distance.is_none_or(|current| via_k < current) {
+                        *distance = Some(via_k);
+                    }
+                }
+            }
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Weighted edges of a small directed graph
+    fn graph(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 7), ('c', 2)],
+            'b' => vec![('d', 1)],
+            'c' => vec![('b', 3), ('d', 8)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let result = bfs(
+            [0],
+            |&n| [n + 1, n * 2].into_iter().filter(|&m| m <= 20),
+            |&n| n == 10,
+        );
+        assert_eq!(result.goal_distance(), Some(5));
+        assert_eq!(result.goal_path(), Some(vec![0, 1, 2, 4, 5, 10]));
+        assert_eq!(result.path_to(&21), None);
+    }
+
+    #[test]
+    fn test_bfs_multiple_starts() {
+        let result = bfs([0, 8], |&n| [n + 1], |&n| n == 10);
+        assert_eq!(result.goal_distance(), Some(2));
+        assert_eq!(result.goal_path(), Some(vec![8, 9, 10]));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let result = dijkstra(['a'], graph, |&node| node == 'd');
+        assert_eq!(result.goal_distance(), Some(6));
+        assert_eq!(result.goal_path(), Some(vec!['a', 'c', 'b', 'd']));
+
+        let result = dijkstra(['a'], graph, |_| false);
+        assert_eq!(result.goal(), None);
+        assert_eq!(result.distance(&'b'), Some(5));
+        assert_eq!(result.predecessors()[&'b'], 'c');
+        assert_eq!(result.distances().len(), 4);
+    }
+
+    #[test]
+    fn test_astar() {
+        let goal = (3, 4);
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x, y + 1), (x - 1, y), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y) && (x, y) != (2, 2))
+                .map(|coord| (coord, 1))
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs();
+        let result = astar([(0, 0)], neighbours, heuristic, |&coord| coord == goal);
+        assert_eq!(result.goal_distance(), Some(7));
+        assert_eq!(result.goal_path().map(|path| path.len()), Some(8));
+    }
+
+    #[test]
+    fn test_all_pairs() {
+        let distances = all_pairs(4, [(0, 1, 7), (0, 2, 2), (2, 1, 3), (1, 3, 1), (2, 3, 8)]);
+        assert_eq!(distances[0][3], Some(6));
+        assert_eq!(distances[2][3], Some(4));
+        assert_eq!(distances[1][1], Some(0));
+        assert_eq!(distances[3][0], None);
+    }
+}
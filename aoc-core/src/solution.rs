@@ -0,0 +1,226 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{grid::Grid, ocr::recognize_grid, ParseError};
+
+/// The answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// Pixels drawn with `#` and `.`, together with the letters they spell if those can be read
+    Screen {
+        text: Option<String>,
+        grid: String,
+    },
+    /// The input parsed, but could not be solved
+    Error(String),
+    Unsolved,
+}
+
+impl Answer {
+    /// Answer shown on a screen of lit pixels
+    pub fn screen(pixels: &Grid<bool>) -> Self {
+        let grid = pixels
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|&pixel| if pixel { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Answer::Screen {
+            text: recognize_grid(pixels),
+            grid,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Screen {
+                text: Some(text), ..
+            } => write!(f, "{text}"),
+            Answer::Screen { text: None, grid } => write!(f, "{grid}"),
+            Answer::Error(message) => write!(f, "error: {message}"),
+            Answer::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::Unsolved, Into::into)
+    }
+}
+
+impl<T: Into<Answer>, E: Display> From<Result<T, E>> for Answer {
+    fn from(value: Result<T, E>) -> Self {
+        value.map_or_else(|err| Answer::Error(err.to_string()), Into::into)
+    }
+}
+
+pub trait Solution {
+    /// Parsed form of the puzzle input, which may borrow from the raw input
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part_one(input: &Self::Input<'_>) -> Answer;
+
+    fn part_two(_input: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+/// Time spent on each stage of a single run, where unsolved or failed parts are not timed
+#[derive(Debug, Clone, Copy)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Option<Duration>,
+    pub part_two: Option<Duration>,
+}
+
+/// Entry in a year's registry of solved days
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    solve: fn(u8, &str) -> Result<Answer, ParseError>,
+    time: fn(&str) -> Result<Timings, ParseError>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(day: u8) -> Self {
+        Day {
+            day,
+            solve: solve_part::<S>,
+            time: time_stages::<S>,
+        }
+    }
+
+    pub fn solve(&self, part: u8, input: &str) -> Result<Answer, ParseError> {
+        (self.solve)(part, input)
+    }
+
+    /// Parses the input and solves both parts once, timing every stage separately
+    pub fn time(&self, input: &str) -> Result<Timings, ParseError> {
+        (self.time)(input)
+    }
+}
+
+fn solve_part<S: Solution>(part: u8, input: &str) -> Result<Answer, ParseError> {
+    let input = S::parse(input)?;
+    Ok(match part {
+        1 => S::part_one(&input),
+        2 => S::part_two(&input),
+        _ => Answer::Unsolved,
+    })
+}
+
+fn time_stages<S: Solution>(input: &str) -> Result<Timings, ParseError> {
+    let start = Instant::now();
+    let input = S::parse(black_box(input))?;
+    let parse = start.elapsed();
+
+    let time_part = |solve: fn(&S::Input<'_>) -> Answer| {
+        let start = Instant::now();
+        let answer = black_box(solve(&input));
+        let elapsed = start.elapsed();
+        (!matches!(answer, Answer::Unsolved | Answer::Error(_))).then_some(elapsed)
+    };
+
+    Ok(Timings {
+        parse,
+        part_one: time_part(S::part_one),
+        part_two: time_part(S::part_two),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Example;
+
+    impl Solution for Example {
+        type Input<'a> = Vec<usize>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            input
+                .split(',')
+                .map(|number| {
+                    number
+                        .parse()
+                        .map_err(|_| ParseError::new(input, number, "number"))
+                })
+                .collect()
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> Answer {
+            input.iter().sum::<usize>().into()
+        }
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(-12i32).to_string(), "-12");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(Answer::from(None::<usize>).to_string(), "-");
+        assert_eq!(Answer::from(Ok::<_, String>(5u8)), Answer::Number(5));
+        let failed = Answer::from(Err::<u8, _>("no wire a"));
+        assert_eq!(failed.to_string(), "error: no wire a");
+    }
+
+    #[test]
+    fn test_day_solve() {
+        let day = Day::new::<Example>(1);
+        assert_eq!(day.solve(1, "1,2,3"), Ok(Answer::Number(6)));
+        assert_eq!(day.solve(2, "1,2,3"), Ok(Answer::Unsolved));
+
+        let err = day.solve(1, "1,x,3").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+    }
+
+    #[test]
+    fn test_day_time() {
+        let day = Day::new::<Example>(1);
+        let timings = day.time("1,2,3").unwrap();
+        assert!(timings.part_one.is_some());
+        assert!(timings.part_two.is_none());
+        assert!(day.time("1,,3").is_err());
+    }
+}
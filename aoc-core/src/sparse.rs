@@ -0,0 +1,160 @@
+use std::{
+    collections::BTreeMap,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::grid::Coord;
+
+/// Grid without fixed dimensions that only stores occupied cells, indexed by `(x, y)`.
+/// Cells are ordered row by row, so the cells in a range of rows can be found quickly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    // Keyed by `(y, x)` to keep the cells of a row together
+    cells: BTreeMap<Coord<isize>, T>,
+    bounds: Option<(Coord<isize>, Coord<isize>)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: BTreeMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, (x, y): Coord<isize>, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some(((min_x, min_y), (max_x, max_y))) => {
+                ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y)))
+            }
+            None => ((x, y), (x, y)),
+        });
+        self.cells.insert((y, x), value)
+    }
+
+    pub fn get(&self, (x, y): Coord<isize>) -> Option<&T> {
+        self.cells.get(&(y, x))
+    }
+
+    pub fn contains(&self, coord: Coord<isize>) -> bool {
+        self.get(coord).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Smallest and largest corner of the box containing every cell
+    pub fn bounds(&self) -> Option<(Coord<isize>, Coord<isize>)> {
+        self.bounds
+    }
+
+    /// Occupied cells, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Coord<isize>, &T)> {
+        self.cells.iter().map(|(&(y, x), value)| ((x, y), value))
+    }
+
+    /// Occupied cells in the rows within `rows`, row by row
+    pub fn rows(&self, rows: impl RangeBounds<isize>) -> impl Iterator<Item = (Coord<isize>, &T)> {
+        let start = match rows.start_bound() {
+            Bound::Included(&y) => Bound::Included((y, isize::MIN)),
+            Bound::Excluded(&y) => Bound::Excluded((y, isize::MAX)),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+        let end = match rows.end_bound() {
+            Bound::Included(&y) => Bound::Included((y, isize::MAX)),
+            Bound::Excluded(&y) => Bound::Excluded((y, isize::MIN)),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+        self.cells
+            .range((start, end))
+            .map(|(&(y, x), value)| ((x, y), value))
+    }
+
+    /// Draws the cells between the top left and bottom right corner, top row first
+    pub fn render(
+        &self,
+        (left, top): Coord<isize>,
+        (right, bottom): Coord<isize>,
+        render_cell: impl Fn(Option<&T>) -> char,
+    ) -> String {
+        if left > right || top > bottom {
+            return String::new();
+        }
+        let width = (right - left + 1) as usize;
+        let height = (bottom - top + 1) as usize;
+        let empty = render_cell(None);
+
+        let mut pixels = vec![vec![empty; width]; height];
+        for ((x, y), value) in self.rows(top..=bottom) {
+            if (left..=right).contains(&x) {
+                pixels[(y - top) as usize][(x - left) as usize] = render_cell(Some(value));
+            }
+        }
+
+        pixels
+            .into_iter()
+            .map(|row| row.into_iter().chain(['\n']).collect::<String>())
+            .collect()
+    }
+}
+
+impl<T> FromIterator<(Coord<isize>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord<isize>, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (coord, value) in iter {
+            grid.insert(coord, value);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_grid() -> SparseGrid<char> {
+        [((-2, 3), 'a'), ((4, -1), 'b'), ((0, 3), 'c'), ((1, 0), 'd')]
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = example_grid();
+        assert_eq!(grid.bounds(), Some(((-2, -1), (4, 3))));
+        assert_eq!(grid.insert((1, 0), 'e'), Some('d'));
+        assert_eq!(grid.len(), 4);
+        assert_eq!(SparseGrid::<char>::new().bounds(), None);
+    }
+
+    #[test]
+    fn test_rows() {
+        let grid = example_grid();
+        let cells = grid
+            .rows(0..=3)
+            .map(|(_, &value)| value)
+            .collect::<String>();
+        assert_eq!(cells, "dac");
+        let cells = grid.rows(..0).map(|(coord, _)| coord).collect::<Vec<_>>();
+        assert_eq!(cells, vec![(4, -1)]);
+        assert_eq!(grid.rows(1..3).count(), 0);
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = example_grid();
+        let rendered = grid.render((-2, 0), (1, 3), |cell| cell.copied().unwrap_or('.'));
+        assert_eq!(rendered, "...d\n....\n....\na.c.\n");
+    }
+}
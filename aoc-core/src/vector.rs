@@ -0,0 +1,386 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+};
+
+/// Signed integer that can be used as the component of a vector
+pub trait Component:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_component {
+    ($($t:ty),*) => {
+        $(
+            impl Component for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_component!(i8, i16, i32, i64, i128, isize);
+
+/// Integer vector with `N` components
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VecN<T, const N: usize>(pub [T; N]);
+
+pub type Vec2<T = i64> = VecN<T, 2>;
+pub type Vec3<T = i64> = VecN<T, 3>;
+
+impl<T: Component, const N: usize> VecN<T, N> {
+    pub const ZERO: Self = VecN([T::ZERO; N]);
+
+    /// Vectors of length one along every axis, in both directions
+    pub fn unit_vectors() -> impl Iterator<Item = Self> {
+        (0..N).flat_map(|axis| {
+            [-T::ONE, T::ONE].map(|value| {
+                let mut unit = Self::ZERO;
+                unit[axis] = value;
+                unit
+            })
+        })
+    }
+
+    /// Replaces every component by -1, 0 or 1, depending on its sign
+    pub fn signum(self) -> Self {
+        VecN(self.0.map(T::signum))
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> T {
+        (self - other)
+            .0
+            .into_iter()
+            .fold(T::ZERO, |total, value| total + value.abs())
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> T {
+        (self - other)
+            .0
+            .into_iter()
+            .map(T::abs)
+            .max()
+            .unwrap_or(T::ZERO)
+    }
+}
+
+impl<T: Copy> VecN<T, 2> {
+    pub const fn new(x: T, y: T) -> Self {
+        VecN([x, y])
+    }
+
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+}
+
+impl<T: Component> VecN<T, 2> {
+    /// Quarter turn clockwise, with the y axis pointing down as on a screen
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y(), self.x())
+    }
+
+    /// Quarter turn counterclockwise, with the y axis pointing down as on a screen
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y(), -self.x())
+    }
+}
+
+impl<T: Copy> VecN<T, 3> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        VecN([x, y, z])
+    }
+
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> T {
+        self.0[2]
+    }
+}
+
+impl<T: Component> VecN<T, 3> {
+    /// Quarter turn around the x axis, from the y axis towards the z axis
+    pub fn rotate_x(self) -> Self {
+        Self::new(self.x(), -self.z(), self.y())
+    }
+
+    /// Quarter turn around the y axis, from the z axis towards the x axis
+    pub fn rotate_y(self) -> Self {
+        Self::new(self.z(), self.y(), -self.x())
+    }
+
+    /// Quarter turn around the z axis, from the x axis towards the y axis
+    pub fn rotate_z(self) -> Self {
+        Self::new(-self.y(), self.x(), self.z())
+    }
+}
+
+impl<T> From<(T, T)> for VecN<T, 2> {
+    fn from((x, y): (T, T)) -> Self {
+        VecN([x, y])
+    }
+}
+
+impl<T> From<VecN<T, 2>> for (T, T) {
+    fn from(VecN([x, y]): VecN<T, 2>) -> Self {
+        (x, y)
+    }
+}
+
+impl<T, const N: usize> Index<usize> for VecN<T, N> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.0[axis]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for VecN<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.0[axis]
+    }
+}
+
+impl<T: Component, const N: usize> Add for VecN<T, N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        VecN(std::array::from_fn(|axis| self.0[axis] + other.0[axis]))
+    }
+}
+
+impl<T: Component, const N: usize> Sub for VecN<T, N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        VecN(std::array::from_fn(|axis| self.0[axis] - other.0[axis]))
+    }
+}
+
+impl<T: Component, const N: usize> Mul<T> for VecN<T, N> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self::Output {
+        VecN(self.0.map(|value| value * factor))
+    }
+}
+
+impl<T: Component, const N: usize> Neg for VecN<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        VecN(self.0.map(|value| -value))
+    }
+}
+
+impl<T: Component, const N: usize> AddAssign for VecN<T, N> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Component, const N: usize> SubAssign for VecN<T, N> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+/// Direction on a grid, with the y axis pointing down as on a screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().turn_right().turn_right()
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// Step of length one in this direction
+    pub fn to_vec<T: Component>(self) -> Vec2<T> {
+        match self {
+            Direction::Up => Vec2::new(T::ZERO, -T::ONE),
+            Direction::Right => Vec2::new(T::ONE, T::ZERO),
+            Direction::Down => Vec2::new(T::ZERO, T::ONE),
+            Direction::Left => Vec2::new(-T::ONE, T::ZERO),
+        }
+    }
+}
+
+/// Direction including diagonals, with the y axis pointing down as on a screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Compass {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Compass {
+    /// Every direction, clockwise starting at north
+    pub const ALL: [Compass; 8] = [
+        Compass::North,
+        Compass::NorthEast,
+        Compass::East,
+        Compass::SouthEast,
+        Compass::South,
+        Compass::SouthWest,
+        Compass::West,
+        Compass::NorthWest,
+    ];
+
+    /// Step in this direction, which is diagonal for the in-between directions
+    pub fn to_vec<T: Component>(self) -> Vec2<T> {
+        let (x, y) = match self {
+            Compass::North => (0, -1),
+            Compass::NorthEast => (1, -1),
+            Compass::East => (1, 0),
+            Compass::SouthEast => (1, 1),
+            Compass::South => (0, 1),
+            Compass::SouthWest => (-1, 1),
+            Compass::West => (-1, 0),
+            Compass::NorthWest => (-1, -1),
+        };
+        let component = |value: i8| match value {
+            -1 => -T::ONE,
+            1 => T::ONE,
+            _ => T::ZERO,
+        };
+        Vec2::new(component(x), component(y))
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Compass::North,
+            Direction::Right => Compass::East,
+            Direction::Down => Compass::South,
+            Direction::Left => Compass::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Vec2::new(3, -4);
+        let b = Vec2::new(-1, 2);
+        assert_eq!(a + b, Vec2::new(2, -2));
+        assert_eq!(a - b, Vec2::new(4, -6));
+        assert_eq!(a * 2, Vec2::new(6, -8));
+        assert_eq!(-a, Vec2::new(-3, 4));
+        assert_eq!((a - b).signum(), Vec2::new(1, -1));
+
+        let mut c = Vec3::new(1i16, 2, 3);
+        c += Vec3::new(1, 1, 1);
+        c -= Vec3::new(0, 0, 4);
+        assert_eq!(c, Vec3::new(2, 3, 0));
+        assert_eq!(c[1], 3);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = VecN([1, 5, -2, 0]);
+        let b = VecN([4, 1, -2, 1]);
+        assert_eq!(a.manhattan_distance(b), 8);
+        assert_eq!(a.chebyshev_distance(b), 4);
+    }
+
+    #[test]
+    fn test_unit_vectors() {
+        let units = Vec3::<i32>::unit_vectors().collect::<Vec<_>>();
+        assert_eq!(units.len(), 6);
+        assert!(units.contains(&Vec3::new(0, -1, 0)));
+        assert!(units
+            .iter()
+            .all(|unit| unit.manhattan_distance(Vec3::ZERO) == 1));
+    }
+
+    #[test]
+    fn test_rotations() {
+        let up = Direction::Up.to_vec::<i32>();
+        assert_eq!(up.rotate_right(), Direction::Right.to_vec());
+        assert_eq!(up.rotate_left(), Direction::Left.to_vec());
+        assert_eq!(Direction::Left.turn_left(), Direction::Down);
+        assert_eq!(Direction::Up.opposite(), Direction::Down);
+
+        let x = Vec3::new(1, 0, 0);
+        assert_eq!(x.rotate_z(), Vec3::new(0, 1, 0));
+        assert_eq!(x.rotate_y(), Vec3::new(0, 0, -1));
+        assert_eq!(Vec3::new(0, 1, 0).rotate_x(), Vec3::new(0, 0, 1));
+    }
+
+    #[test]
+    fn test_compass() {
+        assert_eq!(Compass::NorthEast.to_vec::<i64>(), Vec2::new(1, -1));
+        assert_eq!(Compass::from(Direction::Left), Compass::West);
+        let sum = Compass::ALL
+            .into_iter()
+            .fold(Vec2::<i64>::ZERO, |total, direction| {
+                total + direction.to_vec()
+            });
+        assert_eq!(sum, Vec2::ZERO);
+    }
+}
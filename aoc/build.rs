@@ -0,0 +1,52 @@
+use std::{env, fs, path::Path};
+
+/// Generates one `example!` invocation per fixture in `examples/<year>/<day>-<number>.txt`,
+/// which `tests/examples.rs` turns into a test
+fn main() {
+    let examples_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("examples");
+    println!("cargo:rerun-if-changed={}", examples_dir.display());
+
+    let mut examples = Vec::new();
+    for year_entry in fs::read_dir(&examples_dir).expect("Could not read examples directory") {
+        let year_path = year_entry
+            .expect("Could not read examples directory")
+            .path();
+        let Some(year) = year_path
+            .file_name()
+            .and_then(|name| name.to_str()?.parse::<u16>().ok())
+        else {
+            continue;
+        };
+
+        for entry in fs::read_dir(&year_path).expect("Could not read examples directory") {
+            let path = entry.expect("Could not read examples directory").path();
+            if path.extension().is_none_or(|extension| extension != "txt") {
+                continue;
+            }
+            let stem = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or("");
+            let Some((day, number)) = stem.split_once('-').and_then(|(day, number)| {
+                Some((day.parse::<u8>().ok()?, number.parse::<u8>().ok()?))
+            }) else {
+                panic!("Example {} is not named <day>-<number>.txt", path.display());
+            };
+            examples.push((year, day, number));
+        }
+    }
+    examples.sort();
+
+    let tests = examples
+        .iter()
+        .map(|(year, day, number)| {
+            format!("example!(example_{year}_{day:02}_{number}, {year}, {day}, {number});\n")
+        })
+        .collect::<String>();
+
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("examples.rs"), tests)
+        .expect("Could not write generated example tests");
+}
@@ -0,0 +1,210 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::Display,
+    fs::{read_to_string, write},
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Overrides the location of `answers.toml`
+pub const ANSWERS_VAR: &str = "AOC_ANSWERS";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rejected {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rejected: Vec<Rejected>,
+}
+
+impl DayAnswers {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    fn set(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part_one = Some(answer.to_string()),
+            2 => self.part_two = Some(answer.to_string()),
+            _ => {}
+        }
+    }
+}
+
+/// Registry of accepted and rejected answers, keyed by year and zero-padded day
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, DayAnswers>>);
+
+impl Answers {
+    pub fn get_path() -> PathBuf {
+        match env::var_os(ANSWERS_VAR) {
+            Some(path) => PathBuf::from(path),
+            None => Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("..")
+                .join("answers.toml"),
+        }
+    }
+
+    /// Loads the registry, which is empty when the file does not exist yet
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        match read_to_string(path) {
+            Ok(contents) => Ok(toml::from_str(&contents)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&DayAnswers> {
+        self.0.get(&year.to_string())?.get(&format!("{day:02}"))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u16, u8, &DayAnswers)> {
+        self.0.iter().flat_map(|(year, days)| {
+            days.iter().filter_map(move |(day, answers)| {
+                Some((year.parse().ok()?, day.parse().ok()?, answers))
+            })
+        })
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: u8, answer: &str, verdict: Verdict) {
+        let answers = self
+            .0
+            .entry(year.to_string())
+            .or_default()
+            .entry(format!("{day:02}"))
+            .or_default();
+
+        match verdict {
+            Verdict::Correct => answers.set(part, answer),
+            _ => answers.rejected.push(Rejected {
+                part,
+                answer: answer.to_string(),
+                verdict,
+            }),
+        }
+    }
+
+    /// Returns the verdict an answer is known to get, based on earlier submissions
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<Verdict> {
+        let answers = self.get(year, day)?;
+
+        if let Some(correct) = answers.get(part) {
+            return Some(if correct == answer {
+                Verdict::Correct
+            } else {
+                Verdict::Wrong
+            });
+        }
+
+        let rejected = answers
+            .rejected
+            .iter()
+            .filter(|rejected| rejected.part == part);
+
+        let mut verdict = None;
+        for rejected in rejected {
+            if rejected.answer == answer {
+                return Some(rejected.verdict);
+            }
+
+            // Numeric answers can be ruled out by earlier bounds
+            let (Ok(number), Ok(bound)) = (answer.parse::<i128>(), rejected.answer.parse::<i128>())
+            else {
+                continue;
+            };
+            match rejected.verdict {
+                Verdict::TooHigh if number > bound => verdict = Some(Verdict::TooHigh),
+                Verdict::TooLow if number < bound => verdict = Some(Verdict::TooLow),
+                _ => {}
+            }
+        }
+
+        verdict
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let mut answers = Answers::default();
+        answers.record(2022, 16, 1, "1651", Verdict::Correct);
+        answers.record(2022, 16, 2, "1704", Verdict::TooLow);
+
+        let contents = toml::to_string(&answers).unwrap();
+        let parsed: Answers = toml::from_str(&contents).unwrap();
+
+        let day = parsed.get(2022, 16).unwrap();
+        assert_eq!(day.get(1), Some("1651"));
+        assert_eq!(day.get(2), None);
+        assert_eq!(day.rejected.len(), 1);
+        assert_eq!(
+            parsed
+                .iter()
+                .map(|(year, day, _)| (year, day))
+                .collect::<Vec<_>>(),
+            vec![(2022, 16)]
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.record(2015, 7, 1, "956", Verdict::Correct);
+        answers.record(2015, 7, 2, "100", Verdict::TooLow);
+        answers.record(2015, 7, 2, "500", Verdict::TooHigh);
+        answers.record(2015, 7, 2, "300", Verdict::Wrong);
+
+        assert_eq!(answers.check(2015, 7, 1, "956"), Some(Verdict::Correct));
+        assert_eq!(answers.check(2015, 7, 1, "957"), Some(Verdict::Wrong));
+        assert_eq!(answers.check(2015, 7, 2, "300"), Some(Verdict::Wrong));
+        assert_eq!(answers.check(2015, 7, 2, "50"), Some(Verdict::TooLow));
+        assert_eq!(answers.check(2015, 7, 2, "501"), Some(Verdict::TooHigh));
+        assert_eq!(answers.check(2015, 7, 2, "200"), None);
+        assert_eq!(answers.check(2015, 8, 1, "1"), None);
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    fs::{read_to_string, write},
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc_core::{Day, ParseError};
+use serde::{Deserialize, Serialize};
+
+/// Overrides the location of `bench.json`
+pub const BENCH_VAR: &str = "AOC_BENCH";
+
+/// Slowdowns below this are considered noise, no matter how large they are relatively
+const MIN_REGRESSION: Duration = Duration::from_micros(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub median_ns: u64,
+    pub min_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Some(Stats {
+            median_ns: median.as_nanos() as u64,
+            min_ns: samples[0].as_nanos() as u64,
+        })
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    pub fn min(&self) -> Duration {
+        Duration::from_nanos(self.min_ns)
+    }
+
+    /// Relative change of the median compared to an earlier run
+    pub fn get_change(&self, previous: &Stats) -> f64 {
+        (self.median_ns as f64 - previous.median_ns as f64) / previous.median_ns.max(1) as f64
+    }
+
+    pub fn is_regression(&self, previous: &Stats, threshold: f64) -> bool {
+        self.median() > previous.median() + MIN_REGRESSION && self.get_change(previous) > threshold
+    }
+}
+
+/// Timings of a day, where parts that are not solved yet have no stats
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub iterations: usize,
+    pub parse: Stats,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<Stats>,
+}
+
+impl DayBench {
+    pub fn stages(&self) -> [(&'static str, Option<Stats>); 3] {
+        [
+            ("parse", Some(self.parse)),
+            ("part one", self.part_one),
+            ("part two", self.part_two),
+        ]
+    }
+}
+
+/// Runs every stage of a day `iterations` times
+pub fn bench_day(day: &Day, input: &str, iterations: usize) -> Result<DayBench, ParseError> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part_one = Vec::with_capacity(iterations);
+    let mut part_two = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations.max(1) {
+        let timings = day.time(input)?;
+        parse.push(timings.parse);
+        part_one.extend(timings.part_one);
+        part_two.extend(timings.part_two);
+    }
+
+    Ok(DayBench {
+        iterations: parse.len(),
+        parse: Stats::from_samples(&mut parse).expect("Benchmark ran at least once"),
+        part_one: Stats::from_samples(&mut part_one),
+        part_two: Stats::from_samples(&mut part_two),
+    })
+}
+
+/// Results of the latest benchmark of every day, keyed by year and zero-padded day
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct BenchResults(BTreeMap<String, BTreeMap<String, DayBench>>);
+
+impl BenchResults {
+    pub fn get_path() -> PathBuf {
+        match env::var_os(BENCH_VAR) {
+            Some(path) => PathBuf::from(path),
+            None => Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("..")
+                .join("bench.json"),
+        }
+    }
+
+    /// Loads earlier results, which are empty when nothing has been benchmarked yet
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        match read_to_string(path) {
+            Ok(contents) => Ok(serde_json::from_str(&contents)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(BenchResults::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        write(path, serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(())
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&DayBench> {
+        self.0.get(&year.to_string())?.get(&format!("{day:02}"))
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, bench: DayBench) {
+        self.0
+            .entry(year.to_string())
+            .or_default()
+            .insert(format!("{day:02}"), bench);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(median_ns: u64) -> Stats {
+        Stats {
+            median_ns,
+            min_ns: median_ns / 2,
+        }
+    }
+
+    #[test]
+    fn test_from_samples() {
+        let mut samples = [5, 1, 4, 2].map(Duration::from_nanos);
+        let expected = Stats {
+            median_ns: 3,
+            min_ns: 1,
+        };
+        assert_eq!(Stats::from_samples(&mut samples), Some(expected));
+
+        let mut samples = [7, 3, 9].map(Duration::from_nanos);
+        let expected = Stats {
+            median_ns: 7,
+            min_ns: 3,
+        };
+        assert_eq!(Stats::from_samples(&mut samples), Some(expected));
+
+        assert_eq!(Stats::from_samples(&mut []), None);
+    }
+
+    #[test]
+    fn test_regression() {
+        let previous = stats(100_000);
+        assert!(stats(120_000).is_regression(&previous, 0.1));
+        assert!(!stats(105_000).is_regression(&previous, 0.1));
+        assert!(!stats(50_000).is_regression(&previous, 0.1));
+
+        // Doubling a tiny stage is still noise
+        assert!(!stats(400).is_regression(&stats(200), 0.1));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut results = BenchResults::default();
+        let bench = DayBench {
+            iterations: 10,
+            parse: stats(1_000),
+            part_one: Some(stats(20_000)),
+            part_two: None,
+        };
+        results.insert(2022, 17, bench.clone());
+
+        let contents = serde_json::to_string(&results).unwrap();
+        let parsed: BenchResults = serde_json::from_str(&contents).unwrap();
+        assert_eq!(parsed.get(2022, 17), Some(&bench));
+        assert_eq!(parsed.get(2022, 16), None);
+    }
+}
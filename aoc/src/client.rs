@@ -0,0 +1,153 @@
+use std::{
+    env,
+    fmt::Display,
+    io, thread,
+    time::{Duration, Instant},
+};
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/mirrevdwal/advent-of-code";
+
+/// Minimum time between two requests to the server
+pub const REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    NotAvailable,
+    Unauthorized,
+    Throttled,
+    Status(u16),
+    Transport(String),
+    Io(io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::MissingSession => {
+                write!(f, "No session cookie found, set {SESSION_VAR}")
+            }
+            ClientError::NotAvailable => write!(f, "Puzzle is not available (yet)"),
+            ClientError::Unauthorized => write!(f, "Session cookie was rejected"),
+            ClientError::Throttled => write!(f, "Server asked to slow down, try again later"),
+            ClientError::Status(status) => write!(f, "Server responded with HTTP status {status}"),
+            ClientError::Transport(err) => write!(f, "Could not reach server: {err}"),
+            ClientError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(err: io::Error) -> Self {
+        ClientError::Io(err)
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Status(400 | 401 | 403, _) => ClientError::Unauthorized,
+            ureq::Error::Status(404, _) => ClientError::NotAvailable,
+            ureq::Error::Status(429, _) => ClientError::Throttled,
+            ureq::Error::Status(status, _) => ClientError::Status(status),
+            ureq::Error::Transport(transport) => ClientError::Transport(transport.to_string()),
+        }
+    }
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            last_request: None,
+        }
+    }
+
+    /// Creates a client from the `AOC_SESSION` and `AOC_BASE_URL` environment variables
+    pub fn from_env() -> Result<Self, ClientError> {
+        let session = env::var(SESSION_VAR).map_err(|_| ClientError::MissingSession)?;
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+
+        Ok(Client::new(&base_url, session.trim()))
+    }
+
+    pub fn get_input(&mut self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.throttle();
+
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?;
+
+        Ok(response.into_string()?)
+    }
+
+    /// Posts an answer and returns the body of the response page
+    pub fn submit_answer(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        self.throttle();
+
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+
+        Ok(response.into_string()?)
+    }
+
+    fn throttle(&mut self) {
+        if let Some(last_request) = self.last_request {
+            let elapsed = last_request.elapsed();
+            if elapsed < REQUEST_INTERVAL {
+                thread::sleep(REQUEST_INTERVAL - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status_error(status: u16) -> ClientError {
+        let response = ureq::Response::new(status, "", "").unwrap();
+        ureq::Error::Status(status, response).into()
+    }
+
+    #[test]
+    fn test_status_errors() {
+        assert!(matches!(status_error(400), ClientError::Unauthorized));
+        assert!(matches!(status_error(403), ClientError::Unauthorized));
+        assert!(matches!(status_error(404), ClientError::NotAvailable));
+        assert!(matches!(status_error(429), ClientError::Throttled));
+
+        let err = status_error(500);
+        assert!(matches!(err, ClientError::Status(500)));
+        assert_eq!(err.to_string(), "Server responded with HTTP status 500");
+    }
+}
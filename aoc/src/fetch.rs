@@ -0,0 +1,36 @@
+use std::fs::{create_dir_all, write};
+
+use aoc_core::input::get_input_path;
+
+use crate::client::{Client, ClientError};
+
+pub enum FetchStatus {
+    Downloaded,
+    Cached,
+}
+
+/// Downloads the input of a day into the data directory, unless it is already there.
+/// The client is only created once a download is needed, so cached inputs need no session.
+pub fn fetch_input(
+    client: &mut Option<Client>,
+    year: u16,
+    day: u8,
+) -> Result<FetchStatus, ClientError> {
+    let path = get_input_path(year, day);
+    if path.exists() {
+        return Ok(FetchStatus::Cached);
+    }
+
+    let client = match client {
+        Some(client) => client,
+        None => client.insert(Client::from_env()?),
+    };
+    let input = client.get_input(year, day)?;
+
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    write(&path, input)?;
+
+    Ok(FetchStatus::Downloaded)
+}
@@ -0,0 +1,553 @@
+mod answers;
+mod bench;
+mod client;
+mod fetch;
+mod report;
+mod scaffold;
+mod selection;
+mod submit;
+
+use std::{
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use answers::{Answers, Verdict};
+use aoc_core::{input::InputSource, Answer, Day};
+use bench::{bench_day, BenchResults};
+use clap::{Parser, Subcommand, ValueEnum};
+use client::Client;
+use fetch::{fetch_input, FetchStatus};
+use report::PartReport;
+use scaffold::{create_day, get_workspace_dir};
+use selection::DaySelection;
+use submit::{parse_response, SubmitResponse};
+
+const YEARS: &[(u16, &[Day])] = &[
+    (2015, aoc2015::DAYS),
+    (2021, aoc2021::DAYS),
+    (2022, aoc2022::DAYS),
+    (2023, aoc2023::DAYS),
+];
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Table for reading in a terminal
+    Text,
+    /// One JSON object per part
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions of a year
+    Run {
+        year: u16,
+        /// Day or range of days, such as `7`, `1..=18` or `5..` (defaults to all days)
+        days: Option<DaySelection>,
+        /// Only run a single part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the input from this file instead, or from stdin when given `-`
+        #[arg(long)]
+        input: Option<InputSource>,
+        /// Print a table, or one JSON object per part for scripts
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Download puzzle inputs into the data directory, skipping inputs that are already there
+    Fetch {
+        year: u16,
+        /// Day or range of days, such as `7`, `1..=18` or `5..` (defaults to all days)
+        days: Option<DaySelection>,
+    },
+    /// Run the solutions with recorded answers and compare them against `answers.toml`
+    Verify {
+        year: Option<u16>,
+        /// Day or range of days, such as `7`, `1..=18` or `5..` (defaults to all days)
+        days: Option<DaySelection>,
+    },
+    /// Time the parsing and both parts of every day, and compare against the previous run
+    Bench {
+        year: Option<u16>,
+        /// Day or range of days, such as `7`, `1..=18` or `5..` (defaults to all days)
+        days: Option<DaySelection>,
+        /// Number of times every day is run
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        /// Percentage by which a median has to slow down to be flagged as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Create the module, registry entry and example files of a new day
+    New {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Also download the input of the day
+        #[arg(long)]
+        fetch: bool,
+    },
+    /// Submit an answer and record the response in `answers.toml`
+    Submit {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Answer to submit (defaults to the answer of the solution)
+        answer: Option<String>,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            year,
+            days,
+            part,
+            input,
+            format,
+        } => run(
+            year,
+            days.unwrap_or_else(DaySelection::all),
+            part,
+            input,
+            format,
+        ),
+        Command::Fetch { year, days } => fetch(year, days.unwrap_or_else(DaySelection::all)),
+        Command::Verify { year, days } => verify(year, days.unwrap_or_else(DaySelection::all)),
+        Command::Bench {
+            year,
+            days,
+            iterations,
+            threshold,
+        } => bench(
+            year,
+            days.unwrap_or_else(DaySelection::all),
+            iterations as usize,
+            threshold / 100.0,
+        ),
+        Command::New { year, day, fetch } => new(year, day, fetch),
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        } => submit(year, day, part, answer),
+    }
+}
+
+fn run(
+    year: u16,
+    selection: DaySelection,
+    part: Option<u8>,
+    input: Option<InputSource>,
+    format: Format,
+) -> ExitCode {
+    let Some((_, solutions)) = YEARS.iter().find(|(solved_year, _)| *solved_year == year) else {
+        eprintln!("No solutions found for {year}");
+        return ExitCode::FAILURE;
+    };
+
+    let days = solutions
+        .iter()
+        .filter(|day| selection.contains(day.day))
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        eprintln!("No solutions found for {year} day {selection}");
+        return ExitCode::FAILURE;
+    }
+    if input.is_some() && days.len() > 1 {
+        eprintln!("An input can only be given when running a single day");
+        return ExitCode::FAILURE;
+    }
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut status = ExitCode::SUCCESS;
+
+    if format == Format::Text {
+        print_header();
+    }
+    for day in days {
+        let source = input
+            .clone()
+            .unwrap_or_else(|| InputSource::for_day(year, day.day));
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Could not read input from {source}: {err}");
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        for &part in parts.iter() {
+            let start = Instant::now();
+            match day.solve(part, &input) {
+                Ok(answer) => {
+                    let elapsed = start.elapsed();
+                    if let Answer::Error(err) = &answer {
+                        eprintln!("Could not solve {year} day {} part {part}: {err}", day.day);
+                        status = ExitCode::FAILURE;
+                    }
+                    match format {
+                        Format::Text if matches!(answer, Answer::Error(_)) => {}
+                        Format::Text => print_row(year, day.day, part, &answer, elapsed),
+                        Format::Json => {
+                            let report = PartReport::new(year, day.day, part, &answer, elapsed);
+                            println!("{}", report.to_json());
+                        }
+                    }
+                }
+                Err(err) => {
+                    eprintln!("Could not parse input for {year} day {}: {err}", day.day);
+                    status = ExitCode::FAILURE;
+                    break;
+                }
+            }
+        }
+    }
+
+    status
+}
+
+fn fetch(year: u16, selection: DaySelection) -> ExitCode {
+    let mut client = None;
+
+    for day in selection.days() {
+        match fetch_input(&mut client, year, day) {
+            Ok(FetchStatus::Downloaded) => println!("Downloaded input for {year} day {day}"),
+            Ok(FetchStatus::Cached) => println!("Input for {year} day {day} is already cached"),
+            Err(err) => {
+                eprintln!("Could not fetch input for {year} day {day}: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn new(year: u16, day: u8, download: bool) -> ExitCode {
+    let workspace = get_workspace_dir();
+    let changed = match create_day(&workspace, year, day) {
+        Ok(changed) => changed,
+        Err(err) => {
+            eprintln!("Could not create {year} day {day}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    for path in changed {
+        let path = path.strip_prefix(&workspace).unwrap_or(&path);
+        println!("Wrote {}", path.display());
+    }
+
+    if download {
+        return fetch(year, DaySelection::single(day));
+    }
+    ExitCode::SUCCESS
+}
+
+fn verify(year: Option<u16>, selection: DaySelection) -> ExitCode {
+    let path = Answers::get_path();
+    let answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("Could not load answers from {}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut status = ExitCode::SUCCESS;
+
+    println!(
+        "{:<4}  {:>3}  {:>4}  {:<20}  {:<20}  Result",
+        "Year", "Day", "Part", "Expected", "Answer"
+    );
+    let recorded = answers
+        .iter()
+        .filter(|(answer_year, day, _)| {
+            year.is_none_or(|year| year == *answer_year) && selection.contains(*day)
+        })
+        .filter(|(_, _, day_answers)| day_answers.get(1).is_some() || day_answers.get(2).is_some());
+    for (year, day, day_answers) in recorded {
+        let Some(solution) = get_day(year, day) else {
+            eprintln!("No solution found for {year} day {day}");
+            status = ExitCode::FAILURE;
+            continue;
+        };
+
+        let source = InputSource::for_day(year, day);
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Could not read input from {source}: {err}");
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        for part in 1..=2 {
+            let Some(expected) = day_answers.get(part) else {
+                continue;
+            };
+            let answer = match solution.solve(part, &input) {
+                Ok(Answer::Error(err)) => {
+                    eprintln!("Could not solve {year} day {day} part {part}: {err}");
+                    status = ExitCode::FAILURE;
+                    continue;
+                }
+                Ok(answer) => answer.to_string(),
+                Err(err) => {
+                    eprintln!("Could not parse input for {year} day {day}: {err}");
+                    status = ExitCode::FAILURE;
+                    break;
+                }
+            };
+            let result = if answer == expected {
+                "ok"
+            } else {
+                status = ExitCode::FAILURE;
+                "MISMATCH"
+            };
+            println!("{year:<4}  {day:>3}  {part:>4}  {expected:<20}  {answer:<20}  {result}");
+        }
+    }
+
+    status
+}
+
+fn bench(
+    year: Option<u16>,
+    selection: DaySelection,
+    iterations: usize,
+    threshold: f64,
+) -> ExitCode {
+    let years = YEARS
+        .iter()
+        .filter(|(solved_year, _)| year.is_none_or(|year| year == *solved_year))
+        .collect::<Vec<_>>();
+    if let (Some(year), []) = (year, years.as_slice()) {
+        eprintln!("No solutions found for {year}");
+        return ExitCode::FAILURE;
+    }
+
+    let path = BenchResults::get_path();
+    let mut results = match BenchResults::load(&path) {
+        Ok(results) => results,
+        Err(err) => {
+            eprintln!("Could not load benchmarks from {}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    let mut regressions = 0;
+
+    println!(
+        "{:<4}  {:>3}  {:<8}  {:>10}  {:>10}  {:>10}  Change",
+        "Year", "Day", "Stage", "Median", "Min", "Previous"
+    );
+    for (year, solutions) in years {
+        for day in solutions.iter().filter(|day| selection.contains(day.day)) {
+            let source = InputSource::for_day(*year, day.day);
+            let input = match source.read() {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("Could not read input from {source}: {err}");
+                    status = ExitCode::FAILURE;
+                    continue;
+                }
+            };
+
+            let bench = match bench_day(day, &input, iterations) {
+                Ok(bench) => bench,
+                Err(err) => {
+                    eprintln!("Could not parse input for {year} day {}: {err}", day.day);
+                    status = ExitCode::FAILURE;
+                    continue;
+                }
+            };
+
+            let previous = results
+                .get(*year, day.day)
+                .map(|previous| previous.stages());
+            for (index, (stage, stats)) in bench.stages().into_iter().enumerate() {
+                let Some(stats) = stats else {
+                    continue;
+                };
+
+                let (previous_median, change) = match previous.and_then(|stages| stages[index].1) {
+                    Some(previous) => {
+                        let mut change = format!("{:+.1}%", stats.get_change(&previous) * 100.0);
+                        if stats.is_regression(&previous, threshold) {
+                            change += "  REGRESSION";
+                            regressions += 1;
+                        }
+                        (format!("{:.1?}", previous.median()), change)
+                    }
+                    None => ("-".to_string(), String::new()),
+                };
+                let row = format!(
+                    "{year:<4}  {:>3}  {stage:<8}  {:>10}  {:>10}  {previous_median:>10}  {change}",
+                    day.day,
+                    format!("{:.1?}", stats.median()),
+                    format!("{:.1?}", stats.min()),
+                );
+                println!("{}", row.trim_end());
+            }
+
+            results.insert(*year, day.day, bench);
+        }
+    }
+
+    if let Err(err) = results.save(&path) {
+        eprintln!("Could not save benchmarks to {}: {err}", path.display());
+        return ExitCode::FAILURE;
+    }
+
+    if regressions > 0 {
+        eprintln!("Found {regressions} regression(s) compared to the previous run");
+        status = ExitCode::FAILURE;
+    }
+
+    status
+}
+
+fn submit(year: u16, day: u8, part: u8, answer: Option<String>) -> ExitCode {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let Some(solution) = get_day(year, day) else {
+                eprintln!("No solution found for {year} day {day}");
+                return ExitCode::FAILURE;
+            };
+            let source = InputSource::for_day(year, day);
+            let input = match source.read() {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("Could not read input from {source}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            match solution.solve(part, &input) {
+                Ok(Answer::Error(err)) => {
+                    eprintln!("Could not solve {year} day {day} part {part}: {err}");
+                    return ExitCode::FAILURE;
+                }
+                Ok(answer) => answer.to_string(),
+                Err(err) => {
+                    eprintln!("Could not parse input for {year} day {day}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+    if answer.is_empty() || answer.contains('\n') || answer == Answer::Unsolved.to_string() {
+        eprintln!("Answer {answer:?} can not be submitted");
+        return ExitCode::FAILURE;
+    }
+
+    let path = Answers::get_path();
+    let mut answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("Could not load answers from {}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match answers.check(year, day, part, &answer) {
+        Some(Verdict::Correct) => {
+            println!("{answer} was already accepted for {year} day {day} part {part}");
+            return ExitCode::SUCCESS;
+        }
+        Some(verdict) => {
+            eprintln!("Not submitting {answer}, it is known to be {verdict}");
+            return ExitCode::FAILURE;
+        }
+        None => {}
+    }
+
+    let response =
+        Client::from_env().and_then(|mut client| client.submit_answer(year, day, part, &answer));
+    let verdict = match response.map(|body| parse_response(&body)) {
+        Ok(SubmitResponse::Verdict(verdict)) => verdict,
+        Ok(SubmitResponse::Throttled(wait)) => {
+            let wait = wait.unwrap_or_else(|| "a while".to_string());
+            eprintln!("Submitted too recently, wait {wait} before trying again");
+            return ExitCode::FAILURE;
+        }
+        Ok(SubmitResponse::WrongLevel) => {
+            eprintln!("Part {part} is either already solved or not unlocked yet");
+            return ExitCode::FAILURE;
+        }
+        Ok(SubmitResponse::Unknown) => {
+            eprintln!("Could not interpret the response to the submission");
+            return ExitCode::FAILURE;
+        }
+        Err(err) => {
+            eprintln!("Could not submit answer: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    answers.record(year, day, part, &answer, verdict);
+    if let Err(err) = answers.save(&path) {
+        eprintln!("Could not save answers to {}: {err}", path.display());
+        return ExitCode::FAILURE;
+    }
+
+    println!("{answer} for {year} day {day} part {part} is {verdict}");
+    match verdict {
+        Verdict::Correct => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+fn get_day(year: u16, day: u8) -> Option<&'static Day> {
+    YEARS
+        .iter()
+        .find(|(solved_year, _)| *solved_year == year)?
+        .1
+        .iter()
+        .find(|solution| solution.day == day)
+}
+
+fn print_header() {
+    println!(
+        "{:<4}  {:>3}  {:>4}  {:<20}  {:>10}",
+        "Year", "Day", "Part", "Answer", "Time"
+    );
+}
+
+fn print_row(year: u16, day: u8, part: u8, answer: &Answer, elapsed: Duration) {
+    let text = answer.to_string();
+    let elapsed = format!("{elapsed:.1?}");
+
+    // Screens and other multiline answers are drawn below the row, next to any letters read
+    // from them
+    let (text, drawing) = match answer {
+        Answer::Screen { text, grid } => (text.as_deref().unwrap_or(""), grid.as_str()),
+        _ if text.contains('\n') => ("", text.as_str()),
+        _ => (text.as_str(), ""),
+    };
+    println!("{year:<4}  {day:>3}  {part:>4}  {text:<20}  {elapsed:>10}");
+    for line in drawing.lines() {
+        println!("{:<17}{line}", "");
+    }
+}
@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use aoc_core::Answer;
+use serde::Serialize;
+
+/// Result of one part in `--format json`, printed as one object per line
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct PartReport {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// `None` for parts that are not solved yet
+    pub answer: Option<String>,
+    /// Letters read from an answer that is drawn on a screen, whose `answer` is the grid itself
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ocr: Option<String>,
+    /// Why a part could not be solved, in which case there is no answer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub elapsed_ns: u64,
+}
+
+impl PartReport {
+    pub fn new(year: u16, day: u8, part: u8, answer: &Answer, elapsed: Duration) -> Self {
+        let error = match answer {
+            Answer::Error(message) => Some(message.clone()),
+            _ => None,
+        };
+        let (answer, ocr) = match answer {
+            Answer::Unsolved | Answer::Error(_) => (None, None),
+            Answer::Screen { text, grid } => (Some(grid.clone()), text.clone()),
+            answer => (Some(answer.to_string()), None),
+        };
+
+        PartReport {
+            year,
+            day,
+            part,
+            answer,
+            ocr,
+            error,
+            elapsed_ns: elapsed.as_nanos() as u64,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Report only contains strings and numbers")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::grid::Grid;
+
+    use super::*;
+
+    #[test]
+    fn test_to_json() {
+        let elapsed = Duration::from_micros(12);
+        let report = PartReport::new(2015, 1, 2, &Answer::Number(1771), elapsed);
+        assert_eq!(
+            report.to_json(),
+            r#"{"year":2015,"day":1,"part":2,"answer":"1771","elapsed_ns":12000}"#
+        );
+
+        let report = PartReport::new(2023, 1, 2, &Answer::Unsolved, elapsed);
+        assert_eq!(report.answer, None);
+        assert!(report.to_json().contains(r#""answer":null"#));
+
+        let failed = Answer::Error("Wire a does not exist".to_string());
+        let report = PartReport::new(2015, 7, 1, &failed, elapsed);
+        assert_eq!(
+            report.to_json(),
+            r#"{"year":2015,"day":7,"part":1,"answer":null,"error":"Wire a does not exist","elapsed_ns":12000}"#
+        );
+    }
+
+    #[test]
+    fn test_ocr() {
+        let rows = [
+            ".##..###.",
+            "#..#.#..#",
+            "#..#.###.",
+            "####.#..#",
+            "#..#.#..#",
+            "#..#.###.",
+        ];
+        let pixels = rows
+            .iter()
+            .flat_map(|row| row.chars().map(|pixel| pixel == '#'));
+        let screen = Answer::screen(&Grid::from_cells(9, pixels.collect()));
+        let report = PartReport::new(2022, 10, 2, &screen, Duration::ZERO);
+        assert_eq!(report.answer, Some(rows.join("\n")));
+        assert_eq!(report.ocr.as_deref(), Some("AB"));
+
+        let screen = Answer::screen(&Grid::from_cells(1, vec![true, true]));
+        let report = PartReport::new(2022, 10, 2, &screen, Duration::ZERO);
+        assert_eq!(report.answer.as_deref(), Some("#\n#"));
+        assert_eq!(report.ocr, None);
+
+        let report = PartReport::new(2022, 10, 2, &Answer::from("#\n#"), Duration::ZERO);
+        assert_eq!(report.ocr, None);
+    }
+}
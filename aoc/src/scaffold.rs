@@ -0,0 +1,284 @@
+use std::{
+    env,
+    fmt::Display,
+    fs::{create_dir_all, read_to_string, write},
+    io,
+    path::{Path, PathBuf},
+};
+
+/// Overrides the workspace in which new days are created
+pub const WORKSPACE_VAR: &str = "AOC_WORKSPACE";
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    UnknownYear(u16),
+    DayExists(PathBuf),
+    UnexpectedRegistry(PathBuf),
+    Io(io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::UnknownYear(year) => {
+                write!(
+                    f,
+                    "No crate found for {year}, only days of existing years can be created"
+                )
+            }
+            ScaffoldError::DayExists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::UnexpectedRegistry(path) => write!(
+                f,
+                "{} contains more than the list of days, register the day by hand",
+                path.display()
+            ),
+            ScaffoldError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(err: io::Error) -> Self {
+        ScaffoldError::Io(err)
+    }
+}
+
+pub fn get_workspace_dir() -> PathBuf {
+    match env::var_os(WORKSPACE_VAR) {
+        Some(path) => PathBuf::from(path),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join(".."),
+    }
+}
+
+/// Creates the module of a day, registers it in the year's `DAYS` and adds an empty example.
+/// Returns the created or changed files, and leaves everything untouched if the day exists.
+pub fn create_day(workspace: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let src_dir = workspace.join(year.to_string()).join("src");
+    let lib_path = src_dir.join("lib.rs");
+    let lib = match read_to_string(&lib_path) {
+        Ok(lib) => lib,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(ScaffoldError::UnknownYear(year))
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut days = get_registered_days(&lib);
+    if strip_layout(&render_lib(&days)) != strip_layout(&lib) {
+        return Err(ScaffoldError::UnexpectedRegistry(lib_path));
+    }
+
+    let day_path = src_dir.join(format!("day{day:02}.rs"));
+    if day_path.exists() || days.contains(&day) {
+        return Err(ScaffoldError::DayExists(day_path));
+    }
+
+    days.push(day);
+    days.sort();
+    write(&day_path, render_day(year, day))?;
+    write(&lib_path, render_lib(&days))?;
+    let mut changed = vec![day_path, lib_path];
+
+    let examples_dir = workspace.join("examples").join(year.to_string());
+    create_dir_all(&examples_dir)?;
+    let example_path = examples_dir.join(format!("{day:02}-1.txt"));
+    let answers_path = example_path.with_extension("toml");
+    if !example_path.exists() && !answers_path.exists() {
+        write(&example_path, "")?;
+        write(
+            &answers_path,
+            "# Fill in the example answers to check them with `cargo test`\n# part_one = \n# part_two = \n",
+        )?;
+        changed.extend([example_path, answers_path]);
+    }
+
+    Ok(changed)
+}
+
+fn get_registered_days(lib: &str) -> Vec<u8> {
+    lib.lines()
+        .filter_map(|line| {
+            line.strip_prefix("pub mod day")?
+                .strip_suffix(';')?
+                .parse()
+                .ok()
+        })
+        .collect()
+}
+
+/// Code without whitespace and trailing commas, so every way of laying out the list compares equal
+fn strip_layout(code: &str) -> String {
+    code.split_whitespace()
+        .collect::<String>()
+        .replace(",]", "]")
+}
+
+/// Renders `lib.rs` the way rustfmt formats it
+fn render_lib(days: &[u8]) -> String {
+    let modules = days
+        .iter()
+        .map(|day| format!("pub mod day{day:02};\n"))
+        .collect::<String>();
+    let entries = days
+        .iter()
+        .map(|day| format!("Day::new::<day{day:02}::Day{day:02}>({day})"))
+        .collect::<Vec<_>>();
+
+    let list = entries.join(", ");
+    let registry = if list.len() <= 60 {
+        format!("pub const DAYS: &[Day] = &[{list}];\n")
+    } else {
+        let lines = entries
+            .iter()
+            .map(|entry| format!("    {entry},\n"))
+            .collect::<String>();
+        format!("pub const DAYS: &[Day] = &[\n{lines}];\n")
+    };
+
+    format!("{modules}\nuse aoc_core::Day;\n\n{registry}")
+}
+
+fn render_day(year: u16, day: u8) -> String {
+    TEMPLATE
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+        .replace("{padded_day}", &format!("{day:02}"))
+}
+
+const TEMPLATE: &str = r#"use aoc_core::{parse::parse_lines, Answer, ParseError, Solution};
+
+pub struct Day{padded_day};
+
+impl Solution for Day{padded_day} {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_data(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(_lines: &[&str]) -> Option<usize> {
+    None
+}
+
+fn part_two(_lines: &[&str]) -> Option<usize> {
+    None
+}
+
+fn parse_data(data: &str) -> Result<Vec<&str>, ParseError> {
+    parse_lines(data, Ok)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::read_example;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let data = read_example({year}, {day}, 1);
+        let lines = parse_data(&data).unwrap();
+        assert_eq!(part_one(&lines), None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = read_example({year}, {day}, 1);
+        let lines = parse_data(&data).unwrap();
+        assert_eq!(part_two(&lines), None);
+    }
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_lib() {
+        let lib = "pub mod day01;\n\nuse aoc_core::Day;\n\npub const DAYS: &[Day] = &[Day::new::<day01::Day01>(1)];\n";
+        assert_eq!(get_registered_days(lib), vec![1]);
+        assert_eq!(render_lib(&[1]), lib);
+
+        let lib = render_lib(&[1, 2, 3]);
+        assert!(lib.contains("pub mod day03;\n\nuse aoc_core::Day;"));
+        assert!(lib.contains("&[\n    Day::new::<day01::Day01>(1),\n"));
+        assert_eq!(get_registered_days(&lib), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_create_day() {
+        let workspace = tempfile::tempdir().unwrap();
+        let src_dir = workspace.path().join("2023/src");
+        create_dir_all(&src_dir).unwrap();
+        write(src_dir.join("lib.rs"), render_lib(&[1])).unwrap();
+
+        let changed = create_day(workspace.path(), 2023, 2).unwrap();
+        assert_eq!(changed.len(), 4);
+        assert_eq!(
+            read_to_string(src_dir.join("lib.rs")).unwrap(),
+            render_lib(&[1, 2])
+        );
+        let module = read_to_string(src_dir.join("day02.rs")).unwrap();
+        assert!(module.contains("impl Solution for Day02 {"));
+        assert!(module.contains("read_example(2023, 2, 1)"));
+        assert!(workspace.path().join("examples/2023/02-1.txt").exists());
+
+        assert!(matches!(
+            create_day(workspace.path(), 2023, 2),
+            Err(ScaffoldError::DayExists(_))
+        ));
+        assert!(matches!(
+            create_day(workspace.path(), 2024, 1),
+            Err(ScaffoldError::UnknownYear(2024))
+        ));
+    }
+
+    #[test]
+    fn test_create_day_in_committed_year() {
+        let committed = Path::new(env!("CARGO_MANIFEST_DIR")).join("../2023/src/lib.rs");
+        let committed = read_to_string(committed).unwrap();
+        let mut days = get_registered_days(&committed);
+        let day = (1..=25).find(|day| !days.contains(day)).unwrap();
+        days.push(day);
+        days.sort();
+
+        // The list may also be laid out over several lines
+        let multi_line = committed
+            .replace("&[Day", "&[\n    Day")
+            .replace(")];", "),\n];");
+        assert_ne!(multi_line, committed);
+        for lib in [committed.clone(), multi_line] {
+            let workspace = tempfile::tempdir().unwrap();
+            let src_dir = workspace.path().join("2023/src");
+            create_dir_all(&src_dir).unwrap();
+            write(src_dir.join("lib.rs"), lib).unwrap();
+
+            create_day(workspace.path(), 2023, day).unwrap();
+            assert_eq!(
+                read_to_string(src_dir.join("lib.rs")).unwrap(),
+                render_lib(&days)
+            );
+        }
+
+        let workspace = tempfile::tempdir().unwrap();
+        let src_dir = workspace.path().join("2023/src");
+        create_dir_all(&src_dir).unwrap();
+        write(src_dir.join("lib.rs"), committed + "\nmod helpers;\n").unwrap();
+        assert!(matches!(
+            create_day(workspace.path(), 2023, day),
+            Err(ScaffoldError::UnexpectedRegistry(_))
+        ));
+    }
+}
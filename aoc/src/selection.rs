@@ -0,0 +1,101 @@
+use std::{fmt::Display, ops::RangeInclusive, str::FromStr};
+
+const FIRST_DAY: u8 = 1;
+const LAST_DAY: u8 = 25;
+
+/// Days to run, given as a single day (`7`) or a range (`1..18`, `1..=18`, `5..`, `..=5`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection(RangeInclusive<u8>);
+
+impl DaySelection {
+    pub fn all() -> Self {
+        DaySelection(FIRST_DAY..=LAST_DAY)
+    }
+
+    pub fn single(day: u8) -> Self {
+        DaySelection(day..=day)
+    }
+
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.contains(&day)
+    }
+
+    pub fn days(&self) -> RangeInclusive<u8> {
+        self.0.clone()
+    }
+}
+
+impl Display for DaySelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (start, end) = (self.0.start(), self.0.end());
+        if start == end {
+            write!(f, "{start}")
+        } else {
+            write!(f, "{start}..={end}")
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str, default: u8| {
+            if day.is_empty() {
+                Ok(default)
+            } else {
+                day.parse::<u8>()
+                    .map_err(|_| format!("Could not parse '{day}' as a day"))
+            }
+        };
+
+        let (start, end) = if let Some((start, end)) = value.split_once("..=") {
+            (parse_day(start, FIRST_DAY)?, parse_day(end, LAST_DAY)?)
+        } else if let Some((start, end)) = value.split_once("..") {
+            let end = parse_day(end, LAST_DAY + 1)?
+                .checked_sub(1)
+                .ok_or("Range of days is empty")?;
+            (parse_day(start, FIRST_DAY)?, end)
+        } else {
+            let day = parse_day(value, FIRST_DAY)?;
+            (day, day)
+        };
+
+        if start < FIRST_DAY || end > LAST_DAY {
+            return Err(format!("Days must be between {FIRST_DAY} and {LAST_DAY}"));
+        }
+        if start > end {
+            return Err("Range of days is empty".to_string());
+        }
+
+        Ok(DaySelection(start..=end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_single_day() {
+        assert_eq!("7".parse(), Ok(DaySelection(7..=7)));
+    }
+
+    #[test]
+    fn test_parse_ranges() {
+        assert_eq!("1..=18".parse(), Ok(DaySelection(1..=18)));
+        assert_eq!("1..18".parse(), Ok(DaySelection(1..=17)));
+        assert_eq!("5..".parse(), Ok(DaySelection(5..=25)));
+        assert_eq!("..=5".parse(), Ok(DaySelection(1..=5)));
+        assert_eq!("..".parse(), Ok(DaySelection::all()));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!("0".parse::<DaySelection>().is_err());
+        assert!("26".parse::<DaySelection>().is_err());
+        assert!("10..3".parse::<DaySelection>().is_err());
+        assert!("1..1".parse::<DaySelection>().is_err());
+        assert!("one".parse::<DaySelection>().is_err());
+    }
+}
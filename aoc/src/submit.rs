@@ -0,0 +1,76 @@
+use crate::answers::Verdict;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SubmitResponse {
+    Verdict(Verdict),
+    /// The server asks to wait before submitting again, possibly saying for how long
+    Throttled(Option<String>),
+    /// The part was already solved, or is not unlocked yet
+    WrongLevel,
+    Unknown,
+}
+
+/// Interprets the page returned after posting an answer
+pub fn parse_response(body: &str) -> SubmitResponse {
+    if body.contains("That's the right answer") {
+        SubmitResponse::Verdict(Verdict::Correct)
+    } else if body.contains("That's not the right answer") {
+        if body.contains("your answer is too high") {
+            SubmitResponse::Verdict(Verdict::TooHigh)
+        } else if body.contains("your answer is too low") {
+            SubmitResponse::Verdict(Verdict::TooLow)
+        } else {
+            SubmitResponse::Verdict(Verdict::Wrong)
+        }
+    } else if body.contains("You gave an answer too recently") {
+        let wait = body
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| wait.to_string());
+        SubmitResponse::Throttled(wait)
+    } else if body.contains("You don't seem to be solving the right level") {
+        SubmitResponse::WrongLevel
+    } else {
+        SubmitResponse::Unknown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_verdicts() {
+        assert_eq!(
+            parse_response(
+                "<article><p>That's the right answer! You are one gold star closer.</p></article>"
+            ),
+            SubmitResponse::Verdict(Verdict::Correct)
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer; your answer is too high.</p>"),
+            SubmitResponse::Verdict(Verdict::TooHigh)
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer; your answer is too low.</p>"),
+            SubmitResponse::Verdict(Verdict::TooLow)
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer. If you're stuck, ...</p>"),
+            SubmitResponse::Verdict(Verdict::Wrong)
+        );
+    }
+
+    #[test]
+    fn test_parse_other_responses() {
+        assert_eq!(
+            parse_response("<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 39s left to wait.</p>"),
+            SubmitResponse::Throttled(Some("39s".to_string()))
+        );
+        assert_eq!(
+            parse_response("<p>You don't seem to be solving the right level.  Did you already complete it?</p>"),
+            SubmitResponse::WrongLevel
+        );
+        assert_eq!(parse_response("<html></html>"), SubmitResponse::Unknown);
+    }
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use std::fs;
+
+use common::{aoc, MockServer};
+
+fn write_input(data_dir: &std::path::Path, input: &str) {
+    fs::create_dir_all(data_dir.join("2015")).unwrap();
+    fs::write(data_dir.join("2015/01.txt"), input).unwrap();
+}
+
+#[test]
+fn test_bench_stores_results() {
+    let server = MockServer::with_inputs();
+    let data_dir = tempfile::tempdir().unwrap();
+    write_input(data_dir.path(), "(()(()(");
+
+    let output = aoc(
+        &server,
+        data_dir.path(),
+        &["bench", "2015", "1", "--iterations", "3"],
+    );
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for stage in ["parse", "part one", "part two"] {
+        assert!(stdout.contains(stage), "Missing stage {stage} in {stdout}");
+    }
+
+    let results = fs::read_to_string(data_dir.path().join("bench.json")).unwrap();
+    let results: serde_json::Value = serde_json::from_str(&results).unwrap();
+    assert_eq!(results["2015"]["01"]["iterations"], 3);
+    assert!(results["2015"]["01"]["part_two"]["median_ns"].is_u64());
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn test_bench_flags_regression() {
+    let server = MockServer::with_inputs();
+    let data_dir = tempfile::tempdir().unwrap();
+    write_input(data_dir.path(), &"(()".repeat(1_000_000));
+
+    let previous = r#"{ "2015": { "01": {
+        "iterations": 1,
+        "parse": { "median_ns": 1, "min_ns": 1 },
+        "part_one": { "median_ns": 1, "min_ns": 1 }
+    } } }"#;
+    fs::write(data_dir.path().join("bench.json"), previous).unwrap();
+
+    let output = aoc(
+        &server,
+        data_dir.path(),
+        &["bench", "2015", "1", "--iterations", "3"],
+    );
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let part_one = stdout
+        .lines()
+        .find(|line| line.contains("part one"))
+        .unwrap();
+    assert!(part_one.ends_with("REGRESSION"), "{part_one}");
+
+    // Part two has no earlier result to compare against
+    let part_two = stdout
+        .lines()
+        .find(|line| line.contains("part two"))
+        .unwrap();
+    assert!(!part_two.contains("REGRESSION"), "{part_two}");
+
+    let results = fs::read_to_string(data_dir.path().join("bench.json")).unwrap();
+    assert!(results.contains("\"iterations\": 3"));
+}
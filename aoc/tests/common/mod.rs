@@ -0,0 +1,144 @@
+#![allow(dead_code)]
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::Path,
+    process::{Command, Output},
+    sync::{Arc, Mutex},
+    thread,
+    time::Instant,
+};
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+    pub received: Instant,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+type Handler = dyn Fn(&Request) -> (u16, String) + Send + Sync;
+
+/// Minimal HTTP server standing in for adventofcode.com
+pub struct MockServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+    pub fn start(handler: impl Fn(&Request) -> (u16, String) + Send + Sync + 'static) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Could not bind mock server");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let handler: Arc<Handler> = Arc::new(handler);
+        let server_requests = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                if let Some(request) = read_request(&stream) {
+                    let (status, body) = handler(&request);
+                    server_requests.lock().unwrap().push(request);
+                    write_response(stream, status, &body);
+                }
+            }
+        });
+
+        MockServer { url, requests }
+    }
+
+    /// Serves `<year>/<day>.txt` from the fixture directory at `/<year>/day/<day>/input`
+    pub fn with_inputs() -> Self {
+        MockServer::start(|request| {
+            let segments = request.path.split('/').collect::<Vec<_>>();
+            match segments.as_slice() {
+                ["", year, "day", day, "input"] if request.method == "GET" => {
+                    let day = day.parse::<u8>().unwrap_or_default();
+                    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+                        .join("tests/fixtures/inputs")
+                        .join(year)
+                        .join(format!("{day:02}.txt"));
+                    match std::fs::read_to_string(path) {
+                        Ok(input) => (200, input),
+                        Err(_) => (404, "Not Found".to_string()),
+                    }
+                }
+                _ => (404, "Not Found".to_string()),
+            }
+        })
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn read_request(stream: &TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).ok()?;
+    let received = Instant::now();
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let path = parts.next()?.to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let (key, value) = line.split_once(": ")?;
+        headers.push((key.to_string(), value.to_string()));
+    }
+
+    let length = headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+
+    Some(Request {
+        method,
+        path,
+        headers,
+        body: String::from_utf8_lossy(&body).to_string(),
+        received,
+    })
+}
+
+fn write_response(mut stream: TcpStream, status: u16, body: &str) {
+    let response = format!(
+        "HTTP/1.1 {status} Mock\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+/// Runs the `aoc` binary against the mock server, with inputs, answers and benchmarks stored in `data_dir`
+pub fn aoc(server: &MockServer, data_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .env("AOC_BASE_URL", &server.url)
+        .env("AOC_SESSION", "test-session")
+        .env("AOC_DATA_DIR", data_dir)
+        .env("AOC_ANSWERS", data_dir.join("answers.toml"))
+        .env("AOC_BENCH", data_dir.join("bench.json"))
+        .output()
+        .expect("Could not run aoc binary")
+}
@@ -0,0 +1,69 @@
+use std::fs::read_to_string;
+
+use aoc_core::{
+    input::{get_example_path, read_example},
+    Day,
+};
+use serde::Deserialize;
+
+const YEARS: &[(u16, &[Day])] = &[
+    (2015, aoc2015::DAYS),
+    (2021, aoc2021::DAYS),
+    (2022, aoc2022::DAYS),
+    (2023, aoc2023::DAYS),
+];
+
+/// Expected answers stored next to an example, where parts without an answer are skipped
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+    part_one: Option<toml::Value>,
+    part_two: Option<toml::Value>,
+}
+
+fn check_example(year: u16, day: u8, number: u8) {
+    let solution = YEARS
+        .iter()
+        .find(|(solved_year, _)| *solved_year == year)
+        .and_then(|(_, days)| days.iter().find(|solution| solution.day == day))
+        .unwrap_or_else(|| panic!("No solution registered for {year} day {day}"));
+
+    let input = read_example(year, day, number);
+    let sidecar = get_example_path(year, day, number).with_extension("toml");
+    let expected: Expected = match read_to_string(&sidecar) {
+        Ok(contents) => toml::from_str(&contents)
+            .unwrap_or_else(|err| panic!("Could not parse {}: {err}", sidecar.display())),
+        Err(_) => panic!("Missing expected answers in {}", sidecar.display()),
+    };
+
+    for (part, answer) in [(1, expected.part_one), (2, expected.part_two)] {
+        let Some(answer) = answer else {
+            continue;
+        };
+        let expected = match answer {
+            toml::Value::String(text) => text,
+            value => value.to_string(),
+        };
+
+        let actual = solution
+            .solve(part, &input)
+            .unwrap_or_else(|err| panic!("Could not parse example: {err}"))
+            .to_string();
+        assert_eq!(
+            actual.trim(),
+            expected.trim(),
+            "{year} day {day} example {number} part {part}"
+        );
+    }
+}
+
+macro_rules! example {
+    ($name:ident, $year:expr, $day:expr, $number:expr) => {
+        #[test]
+        fn $name() {
+            check_example($year, $day, $number);
+        }
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
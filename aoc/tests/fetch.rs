@@ -0,0 +1,96 @@
+mod common;
+
+use std::{fs, process::Command};
+
+use common::{aoc, MockServer};
+
+#[test]
+fn test_fetch_downloads_input() {
+    let server = MockServer::with_inputs();
+    let data_dir = tempfile::tempdir().unwrap();
+
+    let output = aoc(&server, data_dir.path(), &["fetch", "2015", "1"]);
+    assert!(output.status.success());
+
+    let input = fs::read_to_string(data_dir.path().join("2015/01.txt")).unwrap();
+    assert_eq!(input, "(()(()(\n");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "/2015/day/1/input");
+    assert_eq!(requests[0].header("Cookie"), Some("session=test-session"));
+    assert!(requests[0].header("User-Agent").is_some());
+}
+
+#[test]
+fn test_fetch_skips_cached_input() {
+    let server = MockServer::with_inputs();
+    let data_dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(data_dir.path().join("2015")).unwrap();
+    fs::write(data_dir.path().join("2015/01.txt"), "cached").unwrap();
+
+    let output = aoc(&server, data_dir.path(), &["fetch", "2015", "1"]);
+    assert!(output.status.success());
+
+    let input = fs::read_to_string(data_dir.path().join("2015/01.txt")).unwrap();
+    assert_eq!(input, "cached");
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn test_fetch_cached_input_needs_no_session() {
+    let data_dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(data_dir.path().join("2015")).unwrap();
+    fs::write(data_dir.path().join("2015/01.txt"), "cached").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["fetch", "2015", "1"])
+        .env_remove("AOC_SESSION")
+        .env("AOC_DATA_DIR", data_dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_fetch_unavailable_input() {
+    let server = MockServer::with_inputs();
+    let data_dir = tempfile::tempdir().unwrap();
+
+    let output = aoc(&server, data_dir.path(), &["fetch", "2015", "3"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("not available"));
+    assert!(!data_dir.path().join("2015/03.txt").exists());
+}
+
+#[test]
+fn test_fetch_throttles_requests() {
+    let server = MockServer::with_inputs();
+    let data_dir = tempfile::tempdir().unwrap();
+
+    let output = aoc(&server, data_dir.path(), &["fetch", "2015", "1..=2"]);
+    assert!(output.status.success());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    let interval = requests[1].received - requests[0].received;
+    assert!(interval.as_millis() >= 900, "Requests {interval:?} apart");
+}
+
+#[test]
+fn test_fetched_input_is_used_by_run() {
+    let server = MockServer::with_inputs();
+    let data_dir = tempfile::tempdir().unwrap();
+
+    assert!(aoc(&server, data_dir.path(), &["fetch", "2015", "2"])
+        .status
+        .success());
+
+    let output = aoc(
+        &server,
+        data_dir.path(),
+        &["run", "2015", "2", "--part", "1"],
+    );
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("101"));
+}
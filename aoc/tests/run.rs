@@ -0,0 +1,33 @@
+mod common;
+
+use std::fs;
+
+use common::{aoc, MockServer};
+
+#[test]
+fn test_run_json() {
+    let server = MockServer::with_inputs();
+    let data_dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(data_dir.path().join("2015")).unwrap();
+    fs::write(data_dir.path().join("2015/01.txt"), "()())").unwrap();
+
+    let output = aoc(
+        &server,
+        data_dir.path(),
+        &["run", "2015", "1", "--format", "json"],
+    );
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let reports = stdout
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(reports.len(), 2);
+    assert_eq!(reports[0]["year"], 2015);
+    assert_eq!(reports[0]["day"], 1);
+    assert_eq!(reports[0]["part"], 1);
+    assert_eq!(reports[0]["answer"], "-1");
+    assert_eq!(reports[1]["answer"], "5");
+    assert!(reports[1]["elapsed_ns"].is_u64());
+}
@@ -0,0 +1,160 @@
+mod common;
+
+use std::fs;
+
+use common::{aoc, MockServer};
+
+/// Fake answer endpoint for which the answer to every part is 3
+fn answer_server() -> MockServer {
+    MockServer::start(|request| {
+        if request.method != "POST" || !request.path.ends_with("/answer") {
+            return (404, "Not Found".to_string());
+        }
+        let answer = request
+            .body
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("answer="))
+            .and_then(|answer| answer.parse::<i64>().ok());
+
+        let message = match answer {
+            Some(3) => "That's the right answer! You are one gold star closer.",
+            Some(answer) if answer > 3 => "That's not the right answer; your answer is too high.",
+            Some(_) => "That's not the right answer; your answer is too low.",
+            None => "That's not the right answer.",
+        };
+        (
+            200,
+            format!("<html><main><article><p>{message}</p></article></main></html>"),
+        )
+    })
+}
+
+fn stdout(output: &std::process::Output) -> String {
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+#[test]
+fn test_submit_records_correct_answer() {
+    let server = answer_server();
+    let data_dir = tempfile::tempdir().unwrap();
+
+    let output = aoc(&server, data_dir.path(), &["submit", "2015", "1", "1", "3"]);
+    assert!(output.status.success());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "/2015/day/1/answer");
+    assert_eq!(requests[0].body, "level=1&answer=3");
+    assert_eq!(requests[0].header("Cookie"), Some("session=test-session"));
+
+    let answers = fs::read_to_string(data_dir.path().join("answers.toml")).unwrap();
+    assert!(answers.contains("[2015.01]"));
+    assert!(answers.contains("part_one = \"3\""));
+}
+
+#[test]
+fn test_submit_records_rejected_answers() {
+    let server = answer_server();
+    let data_dir = tempfile::tempdir().unwrap();
+
+    let output = aoc(
+        &server,
+        data_dir.path(),
+        &["submit", "2015", "1", "2", "10"],
+    );
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("too high"));
+
+    let output = aoc(&server, data_dir.path(), &["submit", "2015", "1", "2", "1"]);
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("too low"));
+
+    let answers = fs::read_to_string(data_dir.path().join("answers.toml")).unwrap();
+    assert!(answers.contains("verdict = \"too_high\""));
+    assert!(answers.contains("verdict = \"too_low\""));
+    assert!(!answers.contains("part_two"));
+}
+
+#[test]
+fn test_submit_skips_known_answers() {
+    let server = answer_server();
+    let data_dir = tempfile::tempdir().unwrap();
+
+    aoc(
+        &server,
+        data_dir.path(),
+        &["submit", "2015", "1", "1", "10"],
+    );
+    assert_eq!(server.requests().len(), 1);
+
+    // Same answer again, and an answer ruled out by the earlier bound
+    let output = aoc(
+        &server,
+        data_dir.path(),
+        &["submit", "2015", "1", "1", "10"],
+    );
+    assert!(!output.status.success());
+    let output = aoc(
+        &server,
+        data_dir.path(),
+        &["submit", "2015", "1", "1", "12"],
+    );
+    assert!(!output.status.success());
+    assert_eq!(server.requests().len(), 1);
+
+    aoc(&server, data_dir.path(), &["submit", "2015", "1", "1", "3"]);
+    let output = aoc(&server, data_dir.path(), &["submit", "2015", "1", "1", "3"]);
+    assert!(output.status.success());
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn test_submit_computes_answer() {
+    let server = answer_server();
+    let data_dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(data_dir.path().join("2015")).unwrap();
+    fs::write(data_dir.path().join("2015/01.txt"), "(()(()(").unwrap();
+
+    let output = aoc(&server, data_dir.path(), &["submit", "2015", "1", "1"]);
+    assert!(output.status.success());
+    assert_eq!(server.requests()[0].body, "level=1&answer=3");
+}
+
+#[test]
+fn test_submit_throttled() {
+    let server = MockServer::start(|_| {
+        (
+            200,
+            "<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 39s left to wait.</p>".to_string(),
+        )
+    });
+    let data_dir = tempfile::tempdir().unwrap();
+
+    let output = aoc(&server, data_dir.path(), &["submit", "2015", "1", "1", "3"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("39s"));
+    assert!(!data_dir.path().join("answers.toml").exists());
+}
+
+#[test]
+fn test_verify() {
+    let server = answer_server();
+    let data_dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(data_dir.path().join("2015")).unwrap();
+    fs::write(data_dir.path().join("2015/01.txt"), "(()(()(").unwrap();
+
+    let answers_path = data_dir.path().join("answers.toml");
+    fs::write(&answers_path, "[2015.01]\npart_one = \"3\"\n").unwrap();
+    let output = aoc(&server, data_dir.path(), &["verify"]);
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("ok"));
+
+    fs::write(
+        &answers_path,
+        "[2015.01]\npart_one = \"3\"\npart_two = \"2\"\n",
+    )
+    .unwrap();
+    let output = aoc(&server, data_dir.path(), &["verify", "2015"]);
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("MISMATCH"));
+}